@@ -0,0 +1,256 @@
+//! ## Asynchronous validation
+//!
+//! [`AsyncValidate`] mirrors [`Validate`][`crate::Validate`] for types which need to perform I/O
+//! during validation, such as checking that a username is not taken.
+//!
+//! ```rust
+//! # use garde::AsyncValidate;
+//! struct Db;
+//!
+//! impl Db {
+//!     async fn username_exists(&self, _: &str) -> bool {
+//!         false
+//!     }
+//! }
+//!
+//! async fn username_available(value: &str, db: &Db) -> garde::Result {
+//!     if db.username_exists(value).await {
+//!         return Err(garde::Error::new("username is taken"));
+//!     }
+//!     Ok(())
+//! }
+//!
+//! #[derive(garde::AsyncValidate)]
+//! #[garde(context(Db))]
+//! struct User<'a> {
+//!     #[garde(ascii, length(min = 3, max = 25), custom_async(username_available))]
+//!     username: &'a str,
+//! }
+//!
+//! async fn handler(user: User<'_>, db: &Db) -> Result<(), garde::Report> {
+//!     user.validate_async_with(db).await
+//! }
+//! ```
+//!
+//! The returned futures do not depend on any particular runtime.
+
+use std::future::Future;
+
+use crate::error::{Path, PathComponentKind};
+use crate::i18n::I18n;
+use crate::Report;
+
+/// The asynchronous counterpart of [`Validate`][`crate::Validate`].
+///
+/// Synchronous rules are still run in place, and `custom_async` rules are awaited one after another,
+/// so all errors end up in a single [`Report`].
+///
+/// Scoped settings such as [groups][`crate::group`] and [partial validation][`crate::with_partial`] are stored
+/// in thread-locals, and are not carried across `.await` points. Error messages can be translated with
+/// [`AsyncValidate::validate_async_with_i18n`] or [`with_i18n_async`][`crate::i18n::with_i18n_async`],
+/// groups activated with [`AsyncValidate::validate_group_async`] or [`with_group_async`][`crate::group::with_group_async`],
+/// and partial validation enabled with [`with_partial_async`][`crate::validate::with_partial_async`], which are.
+pub trait AsyncValidate {
+    /// A user-provided context.
+    ///
+    /// Custom validators receive a reference to this context.
+    type Context;
+
+    /// Validates `Self`, returning an `Err` with an aggregate of all errors if
+    /// the validation failed.
+    ///
+    /// This method should not be implemented manually. Implement [`AsyncValidate::validate_into_async`] instead,
+    /// because [`AsyncValidate::validate_async`] has a default implementation that calls [`AsyncValidate::validate_into_async`].
+    fn validate_async(&self) -> impl Future<Output = Result<(), Report>>
+    where
+        Self::Context: Default,
+    {
+        async move {
+            let ctx = Self::Context::default();
+            self.validate_async_with(&ctx).await
+        }
+    }
+
+    /// Validates `Self`, returning an `Err` with an aggregate of all errors if
+    /// the validation failed.
+    ///
+    /// This method should not be implemented manually. Implement [`AsyncValidate::validate_into_async`] instead,
+    /// because [`AsyncValidate::validate_async_with`] has a default implementation that calls [`AsyncValidate::validate_into_async`].
+    fn validate_async_with(&self, ctx: &Self::Context) -> impl Future<Output = Result<(), Report>> {
+        async move {
+            let mut report = Report::new();
+            self.validate_into_async(ctx, &mut Path::empty, &mut report)
+                .await;
+            match report.is_empty() {
+                true => Ok(()),
+                false => Err(report),
+            }
+        }
+    }
+
+    /// Validates `Self`, returning an `Err` with an aggregate of all errors if
+    /// the validation failed. Error messages are produced by `i18n`, including across `.await` points.
+    ///
+    /// This method should not be implemented manually. Implement [`AsyncValidate::validate_into_async`] instead,
+    /// because [`AsyncValidate::validate_async_with_i18n`] has a default implementation that calls [`AsyncValidate::validate_into_async`].
+    fn validate_async_with_i18n(
+        &self,
+        ctx: &Self::Context,
+        i18n: &(impl I18n + ?Sized),
+    ) -> impl Future<Output = Result<(), Report>> {
+        crate::i18n::with_i18n_async(i18n, self.validate_async_with(ctx))
+    }
+
+    /// Validates `Self` with `G` as the active validation [group][`crate::group`], including across `.await` points,
+    /// returning an `Err` with an aggregate of all errors if the validation failed.
+    ///
+    /// This method should not be implemented manually. Implement [`AsyncValidate::validate_into_async`] instead,
+    /// because [`AsyncValidate::validate_group_async`] has a default implementation that calls [`AsyncValidate::validate_into_async`].
+    fn validate_group_async<G: ?Sized + 'static>(
+        &self,
+        ctx: &Self::Context,
+    ) -> impl Future<Output = Result<(), Report>> {
+        crate::group::with_group_async::<G, _>(self.validate_async_with(ctx))
+    }
+
+    /// Validates `Self`, aggregating all validation errors into `Report`.
+    ///
+    /// `parent` is `Send` so that the returned future can be `Send` as well.
+    fn validate_into_async(
+        &self,
+        ctx: &Self::Context,
+        parent: &mut (dyn FnMut() -> Path + Send),
+        report: &mut Report,
+    ) -> impl Future<Output = ()>;
+}
+
+macro_rules! impl_async_validate_deref {
+    (<$T:ident> $Container:ty) => {
+        impl<$T: ?Sized + AsyncValidate> AsyncValidate for $Container {
+            type Context = $T::Context;
+
+            fn validate_into_async(
+                &self,
+                ctx: &Self::Context,
+                parent: &mut (dyn FnMut() -> Path + Send),
+                report: &mut Report,
+            ) -> impl Future<Output = ()> {
+                <$T as AsyncValidate>::validate_into_async(self, ctx, parent, report)
+            }
+        }
+    };
+}
+
+impl_async_validate_deref!(<T> &T);
+impl_async_validate_deref!(<T> &mut T);
+impl_async_validate_deref!(<T> std::boxed::Box<T>);
+impl_async_validate_deref!(<T> std::rc::Rc<T>);
+impl_async_validate_deref!(<T> std::sync::Arc<T>);
+
+macro_rules! impl_async_validate_list {
+    (<$T:ident $(, $Other:ident)*> $Container:ty) => {
+        impl<$T, $($Other),*> AsyncValidate for $Container
+        where
+            $T: AsyncValidate
+        {
+            type Context = T::Context;
+
+            fn validate_into_async(
+                &self,
+                ctx: &Self::Context,
+                mut parent: &mut (dyn FnMut() -> Path + Send),
+                report: &mut Report,
+            ) -> impl Future<Output = ()> {
+                async move {
+                    for (index, item) in self.iter().enumerate() {
+                        if report.is_full() {
+                            break;
+                        }
+                        let mut path = $crate::util::nested_path!(parent, index);
+                        <T as AsyncValidate>::validate_into_async(item, ctx, &mut path, report).await;
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_async_validate_list!(<T, S> std::collections::HashSet<T, S>);
+impl_async_validate_list!(<T> std::collections::BTreeSet<T>);
+impl_async_validate_list!(<T> std::collections::BinaryHeap<T>);
+impl_async_validate_list!(<T> std::collections::LinkedList<T>);
+impl_async_validate_list!(<T> std::collections::VecDeque<T>);
+impl_async_validate_list!(<T> std::vec::Vec<T>);
+impl_async_validate_list!(<T> [T]);
+
+impl<T: AsyncValidate, const N: usize> AsyncValidate for [T; N] {
+    type Context = T::Context;
+
+    fn validate_into_async(
+        &self,
+        ctx: &Self::Context,
+        parent: &mut (dyn FnMut() -> Path + Send),
+        report: &mut Report,
+    ) -> impl Future<Output = ()> {
+        self.as_slice().validate_into_async(ctx, parent, report)
+    }
+}
+
+impl AsyncValidate for () {
+    type Context = ();
+
+    async fn validate_into_async(
+        &self,
+        _: &Self::Context,
+        _: &mut (dyn FnMut() -> Path + Send),
+        _: &mut Report,
+    ) {
+    }
+}
+
+macro_rules! impl_async_validate_map {
+    (<$K:ident, $V:ident $(, $Other:ident)*> $Container:ty) => {
+        impl<$K, $V, $($Other),*> AsyncValidate for $Container
+        where
+            $K: Clone + PathComponentKind + Sync,
+            $V: AsyncValidate,
+        {
+            type Context = $V::Context;
+
+            fn validate_into_async(
+                &self,
+                ctx: &Self::Context,
+                mut parent: &mut (dyn FnMut() -> Path + Send),
+                report: &mut Report,
+            ) -> impl Future<Output = ()> {
+                async move {
+                    for (key, value) in self.iter() {
+                        if report.is_full() {
+                            break;
+                        }
+                        let mut path = $crate::util::nested_path!(parent, key);
+                        <$V as AsyncValidate>::validate_into_async(value, ctx, &mut path, report).await;
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_async_validate_map!(<K, V, S> std::collections::HashMap<K, V, S>);
+impl_async_validate_map!(<K, V> std::collections::BTreeMap<K, V>);
+
+impl<T: AsyncValidate> AsyncValidate for Option<T> {
+    type Context = T::Context;
+
+    async fn validate_into_async(
+        &self,
+        ctx: &Self::Context,
+        parent: &mut (dyn FnMut() -> Path + Send),
+        report: &mut Report,
+    ) {
+        if let Some(value) = self {
+            value.validate_into_async(ctx, parent, report).await
+        }
+    }
+}
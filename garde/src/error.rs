@@ -10,6 +10,7 @@ use compact_str::{CompactString, ToCompactString};
 use smallvec::SmallVec;
 
 use self::rc_list::List;
+use crate::i18n::I18n;
 
 /// A validation error report.
 ///
@@ -22,20 +23,48 @@ use self::rc_list::List;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Report {
     errors: Vec<(Path, Error)>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    limit: Option<usize>,
 }
 
 impl Report {
     /// Create an empty [`Report`].
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        Self { errors: Vec::new() }
+        Self {
+            errors: Vec::new(),
+            limit: None,
+        }
+    }
+
+    /// Create an empty [`Report`] which holds at most `limit` errors.
+    ///
+    /// Once the limit is reached, further errors are discarded, and validation stops early.
+    /// A `limit` of `0` means no limit, the same as [`Report::new`].
+    pub fn with_limit(limit: usize) -> Self {
+        Self {
+            errors: Vec::new(),
+            limit: (limit != 0).then_some(limit),
+        }
     }
 
     /// Append an [`Error`] into this report at the given [`Path`].
+    ///
+    /// The error is discarded if the report [is full][`Report::is_full`].
     pub fn append(&mut self, path: Path, error: Error) {
+        if self.is_full() {
+            return;
+        }
         self.errors.push((path, error));
     }
 
+    /// Returns `true` if the report has reached its limit, and should not receive any more errors.
+    ///
+    /// `Validate` implementations should check this after appending errors, and stop validating once it is `true`.
+    pub fn is_full(&self) -> bool {
+        self.limit.is_some_and(|limit| self.errors.len() >= limit)
+    }
+
     /// Iterate over all `(Path, Error)` pairs.
     pub fn iter(&self) -> impl Iterator<Item = &(Path, Error)> {
         self.errors.iter()
@@ -50,6 +79,21 @@ impl Report {
     pub fn into_inner(self) -> Vec<(Path, Error)> {
         self.errors
     }
+
+    /// Render every error message in this report again using `i18n`.
+    ///
+    /// Rules keep their parameters, so a report can be validated once, and then shown in several languages.
+    /// See [`Error::localize`].
+    pub fn localize(&self, i18n: &dyn I18n) -> Report {
+        Report {
+            errors: self
+                .errors
+                .iter()
+                .map(|(path, error)| (path.clone(), error.localize(i18n)))
+                .collect(),
+            limit: self.limit,
+        }
+    }
 }
 
 impl std::fmt::Display for Report {
@@ -67,22 +111,521 @@ impl std::fmt::Display for Report {
 
 impl std::error::Error for Report {}
 
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+/// A handle used to append errors into a [`Report`] at, or below, a given [`Path`].
+///
+/// This is what container-level `#[garde(report(...))]` rules receive.
+/// It is scoped to the path of the value being validated, and [`Reporter::at`]
+/// may be used to report an error against one of its fields instead.
+pub struct Reporter<'a> {
+    path: ReporterPath<'a>,
+    report: &'a mut Report,
+    aliases: &'static [(&'static str, &'static str)],
+}
+
+enum ReporterPath<'a> {
+    Lazy(&'a mut dyn FnMut() -> Path),
+    Resolved(Path),
+}
+
+impl<'a> Reporter<'a> {
+    /// Create a [`Reporter`] which appends into `report` at the path produced by `path`.
+    ///
+    /// The path is only computed once an error is actually appended.
+    pub fn new(path: &'a mut dyn FnMut() -> Path, report: &'a mut Report) -> Self {
+        Self {
+            path: ReporterPath::Lazy(path),
+            report,
+            aliases: &[],
+        }
+    }
+
+    /// Set the `(field, alias)` pairs used by [`Reporter::at`], from the `rename` attributes of the fields.
+    #[doc(hidden)]
+    pub fn with_aliases(mut self, aliases: &'static [(&'static str, &'static str)]) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    /// The path this reporter appends errors at.
+    pub fn path(&mut self) -> Path {
+        match &mut self.path {
+            ReporterPath::Lazy(path) => path(),
+            ReporterPath::Resolved(path) => path.clone(),
+        }
+    }
+
+    /// Append an [`Error`] at this reporter's path.
+    pub fn append(&mut self, error: Error) {
+        let path = self.path();
+        self.report.append(path, error);
+    }
+
+    /// Returns a [`Reporter`] scoped to the child `component` of this reporter's path,
+    /// such as the name of a field.
+    ///
+    /// Fields are given by their name in Rust, and are reported at their `rename` alias if they have one.
+    pub fn at<C: PathComponentKind>(&mut self, component: C) -> Reporter<'_> {
+        let alias = match C::component_kind() {
+            Kind::Key => {
+                let field = component.to_compact_string();
+                self.aliases
+                    .iter()
+                    .find_map(|(name, alias)| (*name == field).then_some(*alias))
+            }
+            _ => None,
+        };
+        let path = match alias {
+            Some(alias) => self.path().join(alias),
+            None => self.path().join(component),
+        };
+        Reporter {
+            path: ReporterPath::Resolved(path),
+            report: self.report,
+            aliases: &[],
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Error {
     message: CompactString,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    code: Option<CompactString>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Params::is_empty")
+    )]
+    params: Params,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    rule: Option<Rule>,
+}
+
+// `rule` is left out of comparisons, because it is not serialized and is only used to render the message again.
+impl PartialEq for Error {
+    fn eq(&self, other: &Self) -> bool {
+        (&self.message, &self.code, &self.params) == (&other.message, &other.code, &other.params)
+    }
+}
+
+impl Eq for Error {}
+
+impl PartialOrd for Error {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Error {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (&self.message, &self.code, &self.params).cmp(&(&other.message, &other.code, &other.params))
+    }
+}
+
+/// What rendered the message of an [`Error`], so that [`Error::localize`] can render it again.
+#[derive(Clone, Debug)]
+enum Rule {
+    /// A built-in rule, named after its [`I18n`] method.
+    Builtin(&'static str),
+    /// A message key passed to [`Error::from_key`], and the message used when it has no translation.
+    Custom {
+        key: CompactString,
+        default: CompactString,
+    },
 }
 
 impl Error {
     pub fn new(message: impl ToCompactString) -> Self {
         Self {
             message: message.to_compact_string(),
+            code: None,
+            params: Params::new(),
+            rule: None,
         }
     }
 
+    /// Create an error whose message is looked up by `key` in the [`I18n`] handler installed with
+    /// [`with_i18n`][`crate::with_i18n`], so that custom rules can be translated like built-in ones.
+    ///
+    /// `default` is the message used when the handler has no translation for `key`, see [`I18n::custom`].
+    /// The key is also the error's code.
+    ///
+    /// ```rust
+    /// fn not_reserved(value: &str, _: &()) -> garde::Result {
+    ///     if value == "admin" {
+    ///         return Err(garde::Error::from_key("username_reserved", "username is reserved")
+    ///             .with_param("username", value));
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_key(key: impl ToCompactString, default: impl ToCompactString) -> Self {
+        let key = key.to_compact_string();
+        let default = default.to_compact_string();
+        Self {
+            message: default.clone(),
+            code: Some(key.clone()),
+            params: Params::new(),
+            rule: Some(Rule::Custom { key, default }),
+        }
+        .with_custom_message()
+    }
+
+    /// Record that the message was rendered by the [`I18n`] method called `rule`.
+    pub(crate) fn with_rule(mut self, rule: &'static str) -> Self {
+        self.rule = Some(Rule::Builtin(rule));
+        self
+    }
+
+    /// Render the message of an error created by [`Error::from_key`] using the installed handler.
+    fn with_custom_message(mut self) -> Self {
+        if let Some(Rule::Custom { key, default }) = &self.rule {
+            self.message = match i18n!(custom, key, &self.params) {
+                Some(message) => message.to_compact_string(),
+                None => default.clone(),
+            };
+        }
+        self
+    }
+
+    /// Attach a machine-readable `code` to this error, replacing any existing code.
+    ///
+    /// Errors produced by built-in rules are given a default code, which is the name
+    /// of the corresponding [`I18n`][`crate::I18n`] method, such as `length_lower_than`.
+    pub fn with_code(mut self, code: impl ToCompactString) -> Self {
+        self.code = Some(code.to_compact_string());
+        self
+    }
+
+    /// Replace the message of this error, keeping its code and parameters.
+    ///
+    /// The new message is kept as-is by [`Error::localize`].
+    pub fn with_message(mut self, message: impl ToCompactString) -> Self {
+        self.message = message.to_compact_string();
+        self.rule = None;
+        self
+    }
+
     pub fn message(&self) -> &str {
         self.message.as_ref()
     }
+
+    /// The machine-readable code of this error, if it has one.
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    /// Attach a named parameter to this error, replacing any existing parameter with the same name.
+    ///
+    /// Built-in rules use this to expose the values their messages are rendered from,
+    /// such as the `min` and `max` bounds of `length` and `range`. The message of an error
+    /// created by [`Error::from_key`] is rendered again, so that it can use the parameter.
+    pub fn with_param(mut self, name: impl ToCompactString, value: impl Into<Value>) -> Self {
+        self.params.insert(name, value);
+        self.with_custom_message()
+    }
+
+    /// Attach the name of the `field` a rule refers to, and its `label` attribute if it has one.
+    pub(crate) fn with_field(self, field: &str, label: Option<&str>) -> Self {
+        let error = self.with_param("field", field);
+        match label {
+            Some(label) => error.with_param("label", label),
+            None => error,
+        }
+    }
+
+    /// The parameters attached to this error.
+    pub fn params(&self) -> &Params {
+        &self.params
+    }
+
+    /// The name of the [`I18n`] method which rendered the message of this error, such as `length_lower_than`,
+    /// or the key passed to [`Error::from_key`].
+    ///
+    /// Unlike [`Error::code`], this is not affected by the `code` attribute. It is `None` for errors
+    /// created with [`Error::new`], errors whose message was replaced, and deserialized errors.
+    pub fn rule(&self) -> Option<&str> {
+        match self.rule.as_ref()? {
+            Rule::Builtin(rule) => Some(rule),
+            Rule::Custom { key, .. } => Some(key),
+        }
+    }
+
+    /// Render the message of this error again using `i18n`, from its [rule][`Error::rule`] and [parameters][`Error::params`].
+    ///
+    /// Errors without a rule keep their message.
+    pub fn localize(&self, i18n: &dyn I18n) -> Self {
+        let message = match self.rule.as_ref() {
+            Some(Rule::Builtin(rule)) => crate::i18n::render(i18n, rule, &self.params),
+            Some(Rule::Custom { key, default }) => Some(
+                i18n.custom(key, &self.params)
+                    .unwrap_or_else(|| Cow::Owned(default.to_string())),
+            ),
+            None => None,
+        };
+        match message {
+            Some(message) => Self {
+                message: message.to_compact_string(),
+                ..self.clone()
+            },
+            None => self.clone(),
+        }
+    }
+}
+
+/// Named parameters attached to an [`Error`].
+///
+/// Parameters are kept in insertion order. With the `serde` feature,
+/// they are serialized as a map from name to [`Value`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Params {
+    entries: Vec<(CompactString, Value)>,
+}
+
+impl Params {
+    /// Create an empty set of parameters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert a parameter, replacing any existing parameter with the same name.
+    pub fn insert(&mut self, name: impl ToCompactString, value: impl Into<Value>) {
+        let name = name.to_compact_string();
+        let value = value.into();
+        match self.entries.iter_mut().find(|(k, _)| *k == name) {
+            Some((_, v)) => *v = value,
+            None => self.entries.push((name, value)),
+        }
+    }
+
+    /// Get the value of the parameter called `name`.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.entries.iter().find(|(k, _)| k == name).map(|(_, v)| v)
+    }
+
+    /// Iterate over all `(name, value)` pairs.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.entries.iter().map(|(k, v)| (k.as_str(), v))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// The value of a single [`Error`] parameter.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum Value {
+    Int(i128),
+    Float(f64),
+    Str(CompactString),
+}
+
+impl Value {
+    /// Returns the value as an `i128`, if it is an integer.
+    pub fn as_int(&self) -> Option<i128> {
+        match self {
+            Value::Int(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as an `f64`, if it is a number.
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            Value::Int(v) => Some(*v as f64),
+            Value::Float(v) => Some(*v),
+            Value::Str(_) => None,
+        }
+    }
+
+    /// Returns the value as a `&str`, if it is a string.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::Str(v) => Some(v.as_str()),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Int(v) => v.fmt(f),
+            Value::Float(v) => v.fmt(f),
+            Value::Str(v) => v.fmt(f),
+        }
+    }
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for Value {}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        use std::cmp::Ordering;
+
+        match (self, other) {
+            (Value::Int(a), Value::Int(b)) => a.cmp(b),
+            (Value::Float(a), Value::Float(b)) => a.total_cmp(b),
+            (Value::Str(a), Value::Str(b)) => a.cmp(b),
+            (Value::Int(_), _) => Ordering::Less,
+            (Value::Float(_), Value::Int(_)) => Ordering::Greater,
+            (Value::Float(_), Value::Str(_)) => Ordering::Less,
+            (Value::Str(_), _) => Ordering::Greater,
+        }
+    }
+}
+
+macro_rules! impl_value_from {
+    ($($T:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl From<$T> for Value {
+                fn from(v: $T) -> Self {
+                    Value::$variant(v.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_value_from!(
+    u8 => Int, u16 => Int, u32 => Int, u64 => Int,
+    i8 => Int, i16 => Int, i32 => Int, i64 => Int, i128 => Int,
+    f32 => Float, f64 => Float,
+    &str => Str, String => Str, CompactString => Str,
+);
+
+impl From<usize> for Value {
+    fn from(v: usize) -> Self {
+        Value::Int(v as i128)
+    }
+}
+
+impl From<isize> for Value {
+    fn from(v: isize) -> Self {
+        Value::Int(v as i128)
+    }
+}
+
+impl From<u128> for Value {
+    fn from(v: u128) -> Self {
+        match i128::try_from(v) {
+            Ok(v) => Value::Int(v),
+            Err(_) => Value::Str(v.to_compact_string()),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Params {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap as _;
+
+        let mut map = serializer.serialize_map(Some(self.entries.len()))?;
+        for (name, value) in self.entries.iter() {
+            map.serialize_entry(name, value)?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Value {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = Value;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a number or a string")
+            }
+
+            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> {
+                Ok(Value::from(v))
+            }
+
+            fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E> {
+                Ok(Value::from(v))
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(Value::from(v))
+            }
+
+            fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E> {
+                Ok(Value::from(v))
+            }
+
+            fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E> {
+                Ok(Value::from(v))
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(Value::from(v))
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Params {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = Params;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a map of error parameters")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let mut params = Params::new();
+                while let Some((name, value)) = map.next_entry::<CompactString, Value>()? {
+                    params.insert(name, value);
+                }
+                Ok(params)
+            }
+        }
+
+        deserializer.deserialize_map(Visitor)
+    }
 }
 
 impl std::fmt::Display for Error {
@@ -177,6 +720,28 @@ impl Path {
         }
     }
 
+    /// Format this path as a [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901), e.g. `/items/0/name`.
+    ///
+    /// `~` and `/` in keys are escaped as `~0` and `~1`. Components without a key are left out,
+    /// and the empty path is the empty pointer, which refers to the whole document.
+    pub fn to_json_pointer(&self) -> String {
+        let mut pointer = String::new();
+        for (kind, component) in self.__iter().rev() {
+            if kind == Kind::None {
+                continue;
+            }
+            pointer.push('/');
+            for c in component.chars() {
+                match c {
+                    '~' => pointer.push_str("~0"),
+                    '/' => pointer.push_str("~1"),
+                    c => pointer.push(c),
+                }
+            }
+        }
+        pointer
+    }
+
     #[doc(hidden)]
     pub fn __iter(
         &self,
@@ -298,6 +863,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn path_json_pointer() {
+        assert_eq!(Path::empty().to_json_pointer(), "");
+        assert_eq!(
+            Path::new("items").join(0).join("name").to_json_pointer(),
+            "/items/0/name"
+        );
+        assert_eq!(Path::new("a/b").join("m~n").to_json_pointer(), "/a~1b/m~0n");
+        assert_eq!(
+            Path::new("value").join(NoKey::default()).to_json_pointer(),
+            "/value"
+        );
+    }
+
+    #[test]
+    fn params_insert_replaces() {
+        let error = Error::new("lol").with_param("min", 1).with_param("min", 2);
+        assert_eq!(error.params().len(), 1);
+        assert_eq!(error.params().get("min"), Some(&Value::Int(2)));
+        assert_eq!(error.params().get("max"), None);
+    }
+
     #[test]
     fn report_select() {
         let mut report = Report::new();
@@ -330,12 +917,45 @@ mod tests {
             report.append(Path::new("a").join(0), Error::new("lorem"));
             report.append(Path::new("a").join(1), Error::new("ispum"));
             report.append(Path::new("a").join(2), Error::new("dolor"));
-            report.append(Path::new("b").join("c"), Error::new("dolor"));
+            report.append(
+                Path::new("b").join("c"),
+                Error::new("dolor").with_code("dolor_code"),
+            );
+            report.append(
+                Path::new("b").join("d"),
+                crate::rules::ascii::apply(&"sít", ()).unwrap_err(),
+            );
 
             let de: Report =
                 serde_json::from_str(&serde_json::to_string(&report).unwrap()).unwrap();
 
             assert_eq!(report.errors, de.errors);
         }
+
+        #[test]
+        fn params_are_serialized() {
+            let error = Error::new("lorem")
+                .with_param("min", 10usize)
+                .with_param("max", 2.5f64)
+                .with_param("pattern", "ipsum");
+            let json = serde_json::to_string(&error).unwrap();
+            assert_eq!(
+                json,
+                r#"{"message":"lorem","params":{"min":10,"max":2.5,"pattern":"ipsum"}}"#,
+            );
+            assert_eq!(serde_json::from_str::<Error>(&json).unwrap(), error);
+        }
+
+        #[test]
+        fn code_is_serialized() {
+            assert_eq!(
+                serde_json::to_string(&Error::new("lorem")).unwrap(),
+                r#"{"message":"lorem"}"#,
+            );
+            assert_eq!(
+                serde_json::to_string(&Error::new("lorem").with_code("ipsum")).unwrap(),
+                r#"{"message":"lorem","code":"ipsum"}"#,
+            );
+        }
     }
 }
@@ -0,0 +1,113 @@
+//! Validation groups.
+//!
+//! Rules may be assigned to one or more groups by adding `groups(...)` to the attribute
+//! they are declared in. Those rules are only applied while one of their groups is active,
+//! while rules without any groups are always applied.
+//!
+//! A group is any type, usually an empty struct declared for this purpose. Because groups are
+//! types, a misspelled group is a compile error instead of a rule which silently never applies.
+//!
+//! ```rust
+//! use garde::Validate;
+//!
+//! struct Create;
+//! struct Update;
+//!
+//! #[derive(garde::Validate)]
+//! struct User {
+//!     #[garde(required, groups(Update))]
+//!     id: Option<u64>,
+//!     #[garde(length(min = 1))]
+//!     #[garde(length(min = 8), groups(Create))]
+//!     password: String,
+//! }
+//!
+//! let user = User { id: None, password: "hunter2".into() };
+//! assert!(user.validate().is_ok());
+//! assert!(user.validate_group::<Create>().is_err());
+//! assert!(user.validate_group::<Update>().is_err());
+//! ```
+//!
+//! `groups(...)` only applies to the validation rules in its attribute. Field-level rules such as
+//! `dive`, `rename` or `code` must be placed in a separate attribute, and sanitizers always run.
+//!
+//! The active group is installed for the current thread, so it also applies to any nested
+//! values reached through `dive`. Use [`with_group`] to activate a group around a call which
+//! does not accept one directly, such as [`Unvalidated::validate`][`crate::Unvalidated::validate`],
+//! and [`with_group_async`] to keep it active across the `.await` points of a future.
+
+use std::any::TypeId;
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+thread_local! {
+    static GROUP: Cell<Option<TypeId>> = const { Cell::new(None) };
+}
+
+/// Execute a closure with `G` as the active validation group.
+///
+/// This group is only active for the current thread.
+pub fn with_group<G: ?Sized + 'static, R>(f: impl FnOnce() -> R) -> R {
+    with_group_id(TypeId::of::<G>(), f)
+}
+
+fn with_group_id<R>(group: TypeId, f: impl FnOnce() -> R) -> R {
+    // Stack guard which restores the previous value of `GROUP` on drop.
+    struct Reset {
+        prev: Option<TypeId>,
+    }
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            GROUP.with(|c| c.set(self.prev));
+        }
+    }
+
+    let _reset = Reset {
+        prev: GROUP.with(|c| c.replace(Some(group))),
+    };
+    f()
+}
+
+/// Returns `true` if any of `groups` is the active validation group.
+pub fn is_active(groups: &[TypeId]) -> bool {
+    GROUP.with(|slot| slot.get().is_some_and(|group| groups.contains(&group)))
+}
+
+/// Run a future with `G` as the active validation group. See [`with_group`].
+///
+/// Unlike wrapping the creation of a future in [`with_group`], the group is activated
+/// every time the returned future is polled, so it applies across `.await` points,
+/// even if the future moves between threads or interleaves with other tasks.
+///
+/// ```rust,ignore
+/// let result = garde::group::with_group_async::<Create, _>(user.validate_async_with(&db)).await;
+/// ```
+pub fn with_group_async<G: ?Sized + 'static, F: Future>(future: F) -> WithGroup<F> {
+    WithGroup {
+        group: TypeId::of::<G>(),
+        future,
+    }
+}
+
+/// A future which activates a validation group while it is polled.
+///
+/// Created by [`with_group_async`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WithGroup<F> {
+    group: TypeId,
+    future: F,
+}
+
+impl<F: Future> Future for WithGroup<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `future` is never moved out of `self`, and `group` is not structurally pinned.
+        let this = unsafe { self.get_unchecked_mut() };
+        let future = unsafe { Pin::new_unchecked(&mut this.future) };
+        with_group_id(this.group, || future.poll(cx))
+    }
+}
@@ -0,0 +1,300 @@
+//! ## Serializing reports for HTTP APIs
+//!
+//! A [`Report`] serializes as a list of `(path, error)` pairs. The types in this module serialize it in
+//! the error formats commonly returned by HTTP APIs instead, converting each [`Path`] to the format's
+//! notion of a location:
+//!
+//! - [`ProblemDetails`]: an [RFC 9457](https://www.rfc-editor.org/rfc/rfc9457) problem details object,
+//!   with an `errors` array whose `pointer`s are JSON Pointers in URI fragment form, e.g. `#/items/0/name`.
+//! - [`JsonApi`]: a [JSON:API](https://jsonapi.org/format/#errors) document, with an `errors` array whose
+//!   `source.pointer`s are JSON Pointers into the request document, e.g. `/data/attributes/items/0/name`.
+//! - [`FieldMap`]: an object which maps each path, as displayed by [`Path`], to the messages of its errors,
+//!   e.g. `{"items[0].name": ["length is lower than 3"]}`.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct User {
+//!     #[garde(length(min = 3))]
+//!     name: String,
+//! }
+//!
+//! let report = garde::Validate::validate(&User { name: "Jo".into() }).unwrap_err();
+//! let body = serde_json::to_value(garde::http::ProblemDetails::new(&report)).unwrap();
+//! assert_eq!(
+//!     body,
+//!     serde_json::json!({
+//!         "title": "Unprocessable Content",
+//!         "status": 422,
+//!         "errors": [{
+//!             "detail": "length is lower than 3",
+//!             "pointer": "#/name",
+//!             "code": "length_lower_than",
+//!             "params": { "min": 3 },
+//!         }],
+//!     })
+//! );
+//! ```
+//!
+//! Each error also carries its code and parameters, if it has any.
+
+use serde::ser::{SerializeMap as _, SerializeSeq as _};
+use serde::{Serialize, Serializer};
+
+use crate::error::{Error, Path};
+use crate::Report;
+
+/// Serializes a [`Report`] as an [RFC 9457](https://www.rfc-editor.org/rfc/rfc9457) problem details object.
+///
+/// By default, the `status` is `422` and the `title` is `Unprocessable Content`, which is what RFC 9457
+/// recommends when the `type` is left out. Each error is an object in the `errors` extension member:
+///
+/// ```json
+/// { "detail": "length is lower than 3", "pointer": "#/name", "code": "length_lower_than", "params": { "min": 3 } }
+/// ```
+///
+/// Responses should be sent with the `application/problem+json` content type.
+#[derive(Debug, Clone)]
+pub struct ProblemDetails<'a> {
+    report: &'a Report,
+    type_: Option<String>,
+    title: String,
+    status: u16,
+    detail: Option<String>,
+    instance: Option<String>,
+}
+
+impl<'a> ProblemDetails<'a> {
+    pub fn new(report: &'a Report) -> Self {
+        Self {
+            report,
+            type_: None,
+            title: "Unprocessable Content".into(),
+            status: 422,
+            detail: None,
+            instance: None,
+        }
+    }
+
+    /// Set the `type` URI which identifies the kind of problem.
+    pub fn with_type(mut self, type_: impl Into<String>) -> Self {
+        self.type_ = Some(type_.into());
+        self
+    }
+
+    /// Set the `title`, a short summary of the kind of problem.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Set the `status`, which should be the status code of the response.
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Set the `detail`, a human-readable explanation of this occurrence of the problem.
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    /// Set the `instance` URI which identifies this occurrence of the problem.
+    pub fn with_instance(mut self, instance: impl Into<String>) -> Self {
+        self.instance = Some(instance.into());
+        self
+    }
+}
+
+impl Serialize for ProblemDetails<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        if let Some(type_) = &self.type_ {
+            map.serialize_entry("type", type_)?;
+        }
+        map.serialize_entry("title", &self.title)?;
+        map.serialize_entry("status", &self.status)?;
+        if let Some(detail) = &self.detail {
+            map.serialize_entry("detail", detail)?;
+        }
+        if let Some(instance) = &self.instance {
+            map.serialize_entry("instance", instance)?;
+        }
+        map.serialize_entry("errors", &Errors(self.report, ProblemError))?;
+        map.end()
+    }
+}
+
+struct ProblemError<'a>(&'a Path, &'a Error);
+
+impl Serialize for ProblemError<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let ProblemError(path, error) = *self;
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("detail", error.message())?;
+        map.serialize_entry("pointer", &fragment(&path.to_json_pointer()))?;
+        if let Some(code) = error.code() {
+            map.serialize_entry("code", code)?;
+        }
+        if !error.params().is_empty() {
+            map.serialize_entry("params", error.params())?;
+        }
+        map.end()
+    }
+}
+
+/// Serializes a [`Report`] as a [JSON:API](https://jsonapi.org/format/#errors) error document.
+///
+/// Each error's `source.pointer` is its path, below `/data/attributes` by default. Every error has the
+/// same `status`, which is `"422"` by default, and its parameters are in `meta`:
+///
+/// ```json
+/// {
+///   "status": "422",
+///   "code": "length_lower_than",
+///   "detail": "length is lower than 3",
+///   "source": { "pointer": "/data/attributes/name" },
+///   "meta": { "min": 3 }
+/// }
+/// ```
+///
+/// Responses should be sent with the `application/vnd.api+json` content type.
+#[derive(Debug, Clone)]
+pub struct JsonApi<'a> {
+    report: &'a Report,
+    prefix: String,
+    status: u16,
+}
+
+impl<'a> JsonApi<'a> {
+    pub fn new(report: &'a Report) -> Self {
+        Self {
+            report,
+            prefix: "/data/attributes".into(),
+            status: 422,
+        }
+    }
+
+    /// Set the JSON Pointer which paths are relative to, e.g. `/data/relationships`, or `""` for the document root.
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Set the `status` of every error, which should be the status code of the response.
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.status = status;
+        self
+    }
+}
+
+impl Serialize for JsonApi<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(
+            "errors",
+            &Errors(self.report, |path, error| JsonApiError {
+                api: self,
+                path,
+                error,
+            }),
+        )?;
+        map.end()
+    }
+}
+
+struct JsonApiError<'a> {
+    api: &'a JsonApi<'a>,
+    path: &'a Path,
+    error: &'a Error,
+}
+
+impl Serialize for JsonApiError<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        struct Source(String);
+
+        impl Serialize for Source {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("pointer", &self.0)?;
+                map.end()
+            }
+        }
+
+        let mut map = serializer.serialize_map(None)?;
+        // JSON:API requires the status to be a string.
+        map.serialize_entry("status", &self.api.status.to_string())?;
+        if let Some(code) = self.error.code() {
+            map.serialize_entry("code", code)?;
+        }
+        map.serialize_entry("detail", self.error.message())?;
+        let pointer = format!("{}{}", self.api.prefix, self.path.to_json_pointer());
+        map.serialize_entry("source", &Source(pointer))?;
+        if !self.error.params().is_empty() {
+            map.serialize_entry("meta", self.error.params())?;
+        }
+        map.end()
+    }
+}
+
+/// Serializes a [`Report`] as an object which maps each path to the messages of its errors.
+///
+/// Paths are displayed like in [`Report`]'s `Display` implementation, e.g. `items[0].name`, and errors
+/// on the value itself are under the empty string. Paths keep the order in which they were first reported:
+///
+/// ```json
+/// { "name": ["length is lower than 3", "not ascii"], "items[0]": ["not alphanumeric"] }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct FieldMap<'a>(pub &'a Report);
+
+impl Serialize for FieldMap<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut fields: Vec<(&Path, Vec<&str>)> = Vec::new();
+        for (path, error) in self.0.iter() {
+            match fields.iter_mut().find(|(p, _)| *p == path) {
+                Some((_, messages)) => messages.push(error.message()),
+                None => fields.push((path, vec![error.message()])),
+            }
+        }
+
+        let mut map = serializer.serialize_map(Some(fields.len()))?;
+        for (path, messages) in fields {
+            map.serialize_entry(&path.to_string(), &messages)?;
+        }
+        map.end()
+    }
+}
+
+/// Serializes every error in a report with `f`.
+struct Errors<'a, F>(&'a Report, F);
+
+impl<'a, F, T> Serialize for Errors<'a, F>
+where
+    F: Fn(&'a Path, &'a Error) -> T,
+    T: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.0.iter().count()))?;
+        for (path, error) in self.0.iter() {
+            seq.serialize_element(&(self.1)(path, error))?;
+        }
+        seq.end()
+    }
+}
+
+/// Convert a JSON Pointer to its URI fragment form, as described in
+/// [RFC 6901, section 6](https://www.rfc-editor.org/rfc/rfc6901#section-6).
+fn fragment(pointer: &str) -> String {
+    let mut fragment = String::with_capacity(pointer.len() + 1);
+    fragment.push('#');
+    for byte in pointer.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' => fragment.push(byte as char),
+            b'-' | b'.' | b'_' | b'~' | b'!' | b'$' | b'&' | b'\'' | b'(' | b')' | b'*' | b'+'
+            | b',' | b';' | b'=' | b':' | b'@' | b'/' | b'?' => fragment.push(byte as char),
+            _ => fragment.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    fragment
+}
@@ -48,9 +48,21 @@
 use std::borrow::Cow;
 use std::cell::Cell;
 use std::fmt::Display;
+use std::future::Future;
 use std::mem::transmute;
+use std::pin::Pin;
 use std::ptr::NonNull;
+use std::task::{Context, Poll};
 
+use crate::error::{Params, Value};
+
+#[cfg(feature = "fluent")]
+mod fluent;
+#[cfg(feature = "fluent")]
+pub mod locales;
+
+#[cfg(feature = "fluent")]
+pub use self::fluent::{FluentI18n, FluentI18nError};
 pub use crate::rules::ip::IpKind;
 
 /// Reasons an email value can fail to parse.
@@ -65,6 +77,20 @@ pub enum InvalidEmail {
     InvalidDomain,
 }
 
+impl InvalidEmail {
+    /// A stable, `snake_case` identifier for this reason, suitable for use as an error parameter.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InvalidEmail::Empty => "empty",
+            InvalidEmail::MissingAt => "missing_at",
+            InvalidEmail::UserLengthExceeded => "user_length_exceeded",
+            InvalidEmail::InvalidUser => "invalid_user",
+            InvalidEmail::DomainLengthExceeded => "domain_length_exceeded",
+            InvalidEmail::InvalidDomain => "invalid_domain",
+        }
+    }
+}
+
 impl Display for InvalidEmail {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -99,6 +125,27 @@ pub enum InvalidUrl {
     Other,
 }
 
+impl InvalidUrl {
+    /// A stable, `snake_case` identifier for this reason, suitable for use as an error parameter.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InvalidUrl::EmptyHost => "empty_host",
+            InvalidUrl::IdnaError => "idna_error",
+            InvalidUrl::InvalidPort => "invalid_port",
+            InvalidUrl::InvalidIpv4Address => "invalid_ipv4_address",
+            InvalidUrl::InvalidIpv6Address => "invalid_ipv6_address",
+            InvalidUrl::InvalidDomainCharacter => "invalid_domain_character",
+            InvalidUrl::RelativeUrlWithoutBase => "relative_url_without_base",
+            InvalidUrl::RelativeUrlWithCannotBeABaseBase => {
+                "relative_url_with_cannot_be_a_base_base"
+            }
+            InvalidUrl::SetHostOnCannotBeABaseUrl => "set_host_on_cannot_be_a_base_url",
+            InvalidUrl::Overflow => "overflow",
+            InvalidUrl::Other => "other",
+        }
+    }
+}
+
 impl Display for InvalidUrl {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -132,6 +179,19 @@ pub enum InvalidCreditCard {
     Other,
 }
 
+impl InvalidCreditCard {
+    /// A stable, `snake_case` identifier for this reason, suitable for use as an error parameter.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InvalidCreditCard::InvalidFormat => "invalid_format",
+            InvalidCreditCard::InvalidLength => "invalid_length",
+            InvalidCreditCard::InvalidLuhn => "invalid_luhn",
+            InvalidCreditCard::UnknownType => "unknown_type",
+            InvalidCreditCard::Other => "other",
+        }
+    }
+}
+
 impl Display for InvalidCreditCard {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -160,6 +220,22 @@ pub enum InvalidPhoneNumber {
     Other,
 }
 
+impl InvalidPhoneNumber {
+    /// A stable, `snake_case` identifier for this reason, suitable for use as an error parameter.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InvalidPhoneNumber::Invalid => "invalid",
+            InvalidPhoneNumber::NotANumber => "not_a_number",
+            InvalidPhoneNumber::InvalidCountryCode => "invalid_country_code",
+            InvalidPhoneNumber::TooShortAfterIdd => "too_short_after_idd",
+            InvalidPhoneNumber::TooShortNsn => "too_short_nsn",
+            InvalidPhoneNumber::TooLong => "too_long",
+            InvalidPhoneNumber::MalformedInteger => "malformed_integer",
+            InvalidPhoneNumber::Other => "other",
+        }
+    }
+}
+
 impl Display for InvalidPhoneNumber {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -224,6 +300,41 @@ pub trait I18n {
     /// Rule: `matches`
     fn matches_field_mismatch(&self, field: &dyn Display) -> Cow<'static, str>;
 
+    /// Rule: `not_equal`
+    ///
+    /// Defaults to the english message of [`DefaultI18n`].
+    fn not_equal_field_match(&self, field: &dyn Display) -> Cow<'static, str> {
+        DefaultI18n.not_equal_field_match(field)
+    }
+
+    /// Rule: `greater_than`
+    ///
+    /// Defaults to the english message of [`DefaultI18n`].
+    fn compare_not_greater_than(&self, field: &dyn Display) -> Cow<'static, str> {
+        DefaultI18n.compare_not_greater_than(field)
+    }
+
+    /// Rule: `greater_than_or_equal`
+    ///
+    /// Defaults to the english message of [`DefaultI18n`].
+    fn compare_not_greater_than_or_equal(&self, field: &dyn Display) -> Cow<'static, str> {
+        DefaultI18n.compare_not_greater_than_or_equal(field)
+    }
+
+    /// Rule: `less_than`
+    ///
+    /// Defaults to the english message of [`DefaultI18n`].
+    fn compare_not_less_than(&self, field: &dyn Display) -> Cow<'static, str> {
+        DefaultI18n.compare_not_less_than(field)
+    }
+
+    /// Rule: `less_than_or_equal`
+    ///
+    /// Defaults to the english message of [`DefaultI18n`].
+    fn compare_not_less_than_or_equal(&self, field: &dyn Display) -> Cow<'static, str> {
+        DefaultI18n.compare_not_less_than_or_equal(field)
+    }
+
     /// Rule: `email`
     fn email_invalid(&self, reason: InvalidEmail) -> Cow<'static, str>;
 
@@ -235,6 +346,45 @@ pub trait I18n {
 
     /// Rule: `required`
     fn required_not_set(&self) -> Cow<'static, str>;
+
+    /// Rule: `required_if`
+    ///
+    /// Defaults to the message of [`I18n::required_not_set`], since the condition has no name to show.
+    fn required_if_not_set(&self) -> Cow<'static, str> {
+        self.required_not_set()
+    }
+
+    /// Rule: `required_with`
+    ///
+    /// Defaults to the english message of [`DefaultI18n`].
+    fn required_with_not_set(&self, field: &dyn Display) -> Cow<'static, str> {
+        DefaultI18n.required_with_not_set(field)
+    }
+
+    /// Rule: `required_without`
+    ///
+    /// Defaults to the english message of [`DefaultI18n`].
+    fn required_without_not_set(&self, field: &dyn Display) -> Cow<'static, str> {
+        DefaultI18n.required_without_not_set(field)
+    }
+
+    /// Errors created with [`Error::from_key`][`crate::Error::from_key`], usually by `custom` rules.
+    ///
+    /// Returns the message for `key`, rendered from `params`, or `None` to use the error's default message.
+    /// The default implementation has no translations.
+    fn custom(&self, key: &str, params: &Params) -> Option<Cow<'static, str>> {
+        let _ = (key, params);
+        None
+    }
+
+    /// The name shown for `field` in the messages of rules which refer to it, such as `matches`.
+    ///
+    /// `field` is the name of the field in Rust. Returns `None` to use the field's `label` attribute,
+    /// or its name if it has none. The default implementation has no translations.
+    fn label(&self, field: &str) -> Option<Cow<'static, str>> {
+        let _ = field;
+        None
+    }
 }
 
 impl<T: I18n + ?Sized> I18n for &T {
@@ -291,6 +441,26 @@ impl<T: I18n + ?Sized> I18n for &T {
         (**self).matches_field_mismatch(field)
     }
     #[inline]
+    fn not_equal_field_match(&self, field: &dyn Display) -> Cow<'static, str> {
+        (**self).not_equal_field_match(field)
+    }
+    #[inline]
+    fn compare_not_greater_than(&self, field: &dyn Display) -> Cow<'static, str> {
+        (**self).compare_not_greater_than(field)
+    }
+    #[inline]
+    fn compare_not_greater_than_or_equal(&self, field: &dyn Display) -> Cow<'static, str> {
+        (**self).compare_not_greater_than_or_equal(field)
+    }
+    #[inline]
+    fn compare_not_less_than(&self, field: &dyn Display) -> Cow<'static, str> {
+        (**self).compare_not_less_than(field)
+    }
+    #[inline]
+    fn compare_not_less_than_or_equal(&self, field: &dyn Display) -> Cow<'static, str> {
+        (**self).compare_not_less_than_or_equal(field)
+    }
+    #[inline]
     fn email_invalid(&self, reason: InvalidEmail) -> Cow<'static, str> {
         (**self).email_invalid(reason)
     }
@@ -306,6 +476,26 @@ impl<T: I18n + ?Sized> I18n for &T {
     fn required_not_set(&self) -> Cow<'static, str> {
         (**self).required_not_set()
     }
+    #[inline]
+    fn required_if_not_set(&self) -> Cow<'static, str> {
+        (**self).required_if_not_set()
+    }
+    #[inline]
+    fn required_with_not_set(&self, field: &dyn Display) -> Cow<'static, str> {
+        (**self).required_with_not_set(field)
+    }
+    #[inline]
+    fn required_without_not_set(&self, field: &dyn Display) -> Cow<'static, str> {
+        (**self).required_without_not_set(field)
+    }
+    #[inline]
+    fn custom(&self, key: &str, params: &Params) -> Option<Cow<'static, str>> {
+        (**self).custom(key, params)
+    }
+    #[inline]
+    fn label(&self, field: &str) -> Option<Cow<'static, str>> {
+        (**self).label(field)
+    }
 }
 
 /// Default implementation of [`I18n`] which provides english error messages.
@@ -367,6 +557,26 @@ impl I18n for DefaultI18n {
         format!("does not match {field} field").into()
     }
 
+    fn not_equal_field_match(&self, field: &dyn Display) -> Cow<'static, str> {
+        format!("matches {field} field").into()
+    }
+
+    fn compare_not_greater_than(&self, field: &dyn Display) -> Cow<'static, str> {
+        format!("not greater than {field} field").into()
+    }
+
+    fn compare_not_greater_than_or_equal(&self, field: &dyn Display) -> Cow<'static, str> {
+        format!("not greater than or equal to {field} field").into()
+    }
+
+    fn compare_not_less_than(&self, field: &dyn Display) -> Cow<'static, str> {
+        format!("not less than {field} field").into()
+    }
+
+    fn compare_not_less_than_or_equal(&self, field: &dyn Display) -> Cow<'static, str> {
+        format!("not less than or equal to {field} field").into()
+    }
+
     fn email_invalid(&self, reason: InvalidEmail) -> Cow<'static, str> {
         format!("not a valid email: {reason}").into()
     }
@@ -382,6 +592,14 @@ impl I18n for DefaultI18n {
     fn required_not_set(&self) -> Cow<'static, str> {
         Cow::Borrowed("not set")
     }
+
+    fn required_with_not_set(&self, field: &dyn Display) -> Cow<'static, str> {
+        format!("not set, but {field} field is set").into()
+    }
+
+    fn required_without_not_set(&self, field: &dyn Display) -> Cow<'static, str> {
+        format!("not set, and neither is {field} field").into()
+    }
 }
 
 thread_local! {
@@ -404,6 +622,135 @@ macro_rules! i18n {
     };
 }
 
+/// Create an [`Error`][`crate::Error`] using the message from the currently-installed
+/// [`I18n`] handler. The name of the handler method is used as the error's code and rule.
+macro_rules! i18n_error {
+    ($handler:ident $(, $($args:expr),*)?) => {
+        $crate::error::Error::new(i18n!($handler $(, $($args),*)?))
+            .with_code(stringify!($handler))
+            .with_rule(stringify!($handler))
+    };
+}
+
+/// Render the message of the [`I18n`] method called `rule` from the parameters of an error.
+///
+/// Returns `None` if `rule` is unknown, or a parameter it needs is missing.
+pub(crate) fn render(i18n: &dyn I18n, rule: &str, params: &Params) -> Option<Cow<'static, str>> {
+    let param = |name: &str| params.get(name);
+    let usize = |name: &str| param(name)?.as_int()?.try_into().ok();
+    let reason = |name: &str| param(name)?.as_str();
+    let field = |name: &str| -> Option<Cow<'static, str>> {
+        let field = param(name)?.as_str()?;
+        let label = param("label").and_then(Value::as_str);
+        Some(resolve_label(i18n, field, label))
+    };
+
+    let message = match rule {
+        "length_lower_than" => i18n.length_lower_than(usize("min")?),
+        "length_greater_than" => i18n.length_greater_than(usize("max")?),
+        "range_lower_than" => i18n.range_lower_than(param("min")?),
+        "range_greater_than" => i18n.range_greater_than(param("max")?),
+        "credit_card_invalid" => i18n.credit_card_invalid(parse(
+            &CREDIT_CARD_REASONS,
+            InvalidCreditCard::as_str,
+            reason("reason")?,
+        )?),
+        "pattern_no_match" => i18n.pattern_no_match(param("pattern")?),
+        "contains_missing" => i18n.contains_missing(param("pattern")?),
+        "url_invalid" => {
+            i18n.url_invalid(parse(&URL_REASONS, InvalidUrl::as_str, reason("reason")?)?)
+        }
+        "prefix_missing" => i18n.prefix_missing(param("pattern")?),
+        "suffix_missing" => i18n.suffix_missing(param("pattern")?),
+        "phone_number_invalid" => i18n.phone_number_invalid(parse(
+            &PHONE_NUMBER_REASONS,
+            InvalidPhoneNumber::as_str,
+            reason("reason")?,
+        )?),
+        "ip_invalid" => i18n.ip_invalid(parse(&IP_KINDS, IpKind::as_str, reason("ip_kind")?)?),
+        "matches_field_mismatch" => i18n.matches_field_mismatch(&field("field")?),
+        "not_equal_field_match" => i18n.not_equal_field_match(&field("field")?),
+        "compare_not_greater_than" => i18n.compare_not_greater_than(&field("field")?),
+        "compare_not_greater_than_or_equal" => {
+            i18n.compare_not_greater_than_or_equal(&field("field")?)
+        }
+        "compare_not_less_than" => i18n.compare_not_less_than(&field("field")?),
+        "compare_not_less_than_or_equal" => i18n.compare_not_less_than_or_equal(&field("field")?),
+        "email_invalid" => i18n.email_invalid(parse(
+            &EMAIL_REASONS,
+            InvalidEmail::as_str,
+            reason("reason")?,
+        )?),
+        "ascii_invalid" => i18n.ascii_invalid(),
+        "alphanumeric_invalid" => i18n.alphanumeric_invalid(),
+        "required_not_set" => i18n.required_not_set(),
+        "required_if_not_set" => i18n.required_if_not_set(),
+        "required_with_not_set" => i18n.required_with_not_set(&field("field")?),
+        "required_without_not_set" => i18n.required_without_not_set(&field("field")?),
+        _ => return None,
+    };
+    Some(message)
+}
+
+/// The name `field` is shown as in messages: the handler's [`I18n::label`], then the `label`
+/// attribute of the field, then its name.
+pub(crate) fn field_label(field: &str, label: Option<&str>) -> Cow<'static, str> {
+    i18n!(label, field).unwrap_or_else(|| Cow::Owned(label.unwrap_or(field).to_owned()))
+}
+
+fn resolve_label(i18n: &dyn I18n, field: &str, label: Option<&str>) -> Cow<'static, str> {
+    i18n.label(field)
+        .unwrap_or_else(|| Cow::Owned(label.unwrap_or(field).to_owned()))
+}
+
+fn parse<T: Copy>(variants: &[T], as_str: fn(&T) -> &'static str, value: &str) -> Option<T> {
+    variants.iter().copied().find(|v| as_str(v) == value)
+}
+
+const CREDIT_CARD_REASONS: [InvalidCreditCard; 5] = [
+    InvalidCreditCard::InvalidFormat,
+    InvalidCreditCard::InvalidLength,
+    InvalidCreditCard::InvalidLuhn,
+    InvalidCreditCard::UnknownType,
+    InvalidCreditCard::Other,
+];
+
+const URL_REASONS: [InvalidUrl; 11] = [
+    InvalidUrl::EmptyHost,
+    InvalidUrl::IdnaError,
+    InvalidUrl::InvalidPort,
+    InvalidUrl::InvalidIpv4Address,
+    InvalidUrl::InvalidIpv6Address,
+    InvalidUrl::InvalidDomainCharacter,
+    InvalidUrl::RelativeUrlWithoutBase,
+    InvalidUrl::RelativeUrlWithCannotBeABaseBase,
+    InvalidUrl::SetHostOnCannotBeABaseUrl,
+    InvalidUrl::Overflow,
+    InvalidUrl::Other,
+];
+
+const PHONE_NUMBER_REASONS: [InvalidPhoneNumber; 8] = [
+    InvalidPhoneNumber::Invalid,
+    InvalidPhoneNumber::NotANumber,
+    InvalidPhoneNumber::InvalidCountryCode,
+    InvalidPhoneNumber::TooShortAfterIdd,
+    InvalidPhoneNumber::TooShortNsn,
+    InvalidPhoneNumber::TooLong,
+    InvalidPhoneNumber::MalformedInteger,
+    InvalidPhoneNumber::Other,
+];
+
+const EMAIL_REASONS: [InvalidEmail; 6] = [
+    InvalidEmail::Empty,
+    InvalidEmail::MissingAt,
+    InvalidEmail::UserLengthExceeded,
+    InvalidEmail::InvalidUser,
+    InvalidEmail::DomainLengthExceeded,
+    InvalidEmail::InvalidDomain,
+];
+
+const IP_KINDS: [IpKind; 3] = [IpKind::Any, IpKind::V4, IpKind::V6];
+
 /// Execute a closure with a custom [`I18n`] handler.
 ///
 /// This handler is only installed for the current thread.
@@ -432,3 +779,37 @@ pub fn with_i18n<'a, R>(mut handler: impl I18n + 'a, f: impl FnOnce() -> R) -> R
     };
     f()
 }
+
+/// Run a future with a custom [`I18n`] handler.
+///
+/// Unlike wrapping the creation of a future in [`with_i18n`], the handler is installed
+/// every time the returned future is polled, so it applies across `.await` points,
+/// even if the future moves between threads or interleaves with other tasks.
+///
+/// ```rust,ignore
+/// let result = garde::i18n::with_i18n_async(&german, user.validate_async_with(&db)).await;
+/// ```
+pub fn with_i18n_async<H: I18n, F: Future>(handler: H, future: F) -> WithI18n<H, F> {
+    WithI18n { handler, future }
+}
+
+/// A future which installs an [`I18n`] handler while it is polled.
+///
+/// Created by [`with_i18n_async`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WithI18n<H, F> {
+    handler: H,
+    future: F,
+}
+
+impl<H: I18n, F: Future> Future for WithI18n<H, F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `future` is never moved out of `self`, and `handler` is not structurally pinned.
+        let this = unsafe { self.get_unchecked_mut() };
+        let future = unsafe { Pin::new_unchecked(&mut this.future) };
+        with_i18n(&this.handler, || future.poll(cx))
+    }
+}
@@ -0,0 +1,242 @@
+//! [Fluent](https://projectfluent.org/) backed error messages.
+
+use std::borrow::Cow;
+use std::fmt::{Debug, Display};
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::types::FluentNumber;
+use fluent_bundle::{FluentArgs, FluentError, FluentResource, FluentValue};
+use fluent_syntax::parser::ParserError;
+use unic_langid::LanguageIdentifier;
+
+use crate::error::{Params, Value};
+
+use super::{
+    DefaultI18n, I18n, InvalidCreditCard, InvalidEmail, InvalidPhoneNumber, InvalidUrl, IpKind,
+};
+
+/// An [`I18n`] implementation which formats messages from a Fluent bundle.
+///
+/// Each [`I18n`] method is looked up as a message with the same id, which is also the error's
+/// code, e.g. `length_lower_than`. The rule's parameters are passed as Fluent arguments:
+///
+/// - `min`: `length_lower_than`, `range_lower_than`
+/// - `max`: `length_greater_than`, `range_greater_than`
+/// - `pattern`: `pattern_no_match`, `contains_missing`, `prefix_missing`, `suffix_missing`
+/// - `reason`: `credit_card_invalid`, `url_invalid`, `phone_number_invalid`, `email_invalid`
+/// - `kind`: `ip_invalid`
+/// - `field`: `matches_field_mismatch`, `not_equal_field_match`, `compare_*`, `required_with_not_set`,
+///   `required_without_not_set`
+///
+/// `min` and `max` are Fluent numbers, and `reason` and `kind` are the stable identifiers returned by
+/// `as_str`, e.g. `missing_at` or `ipv4`, so they can be used in selectors. Messages which are missing
+/// from the bundle or fail to format fall back to [`DefaultI18n`], except `required_if_not_set`,
+/// which falls back to `required_not_set`.
+///
+/// The [label][`I18n::label`] of a field referenced by a rule such as `matches` is looked up as
+/// `label_<field>`, e.g. `label_password`.
+///
+/// Errors created with [`Error::from_key`][`crate::Error::from_key`] are looked up by their key,
+/// with the error's parameters as arguments.
+///
+/// ```rust
+/// use garde::i18n::FluentI18n;
+/// use garde::Validate;
+///
+/// #[derive(Validate)]
+/// struct User {
+///     #[garde(length(min = 3))]
+///     name: String,
+///     #[garde(ascii)]
+///     nickname: String,
+/// }
+///
+/// let i18n = FluentI18n::from_ftl(
+///     "cs".parse().unwrap(),
+///     "length_lower_than = musí obsahovat alespoň { $min } znaky",
+/// )
+/// .unwrap();
+///
+/// let user = User { name: "Jo".into(), nickname: "Jožka".into() };
+/// let report = garde::with_i18n(&i18n, || user.validate()).unwrap_err();
+/// assert_eq!(
+///     report.to_string(),
+///     "name: musí obsahovat alespoň 3 znaky\nnickname: not ascii\n"
+/// );
+/// ```
+pub struct FluentI18n {
+    bundle: FluentBundle<FluentResource>,
+}
+
+impl FluentI18n {
+    /// Creates a handler for `locale` without any messages.
+    ///
+    /// Unicode isolation marks around arguments are disabled, as messages are usually displayed as plain text.
+    pub fn new(locale: LanguageIdentifier) -> Self {
+        let mut bundle = FluentBundle::new_concurrent(vec![locale]);
+        bundle.set_use_isolating(false);
+        Self { bundle }
+    }
+
+    /// Creates a handler for `locale` with the messages in `source`.
+    pub fn from_ftl(
+        locale: LanguageIdentifier,
+        source: impl Into<String>,
+    ) -> Result<Self, FluentI18nError> {
+        let mut i18n = Self::new(locale);
+        i18n.add_ftl(source)?;
+        Ok(i18n)
+    }
+
+    /// Creates a handler with the [bundled translations][`super::locales`] for the language of `locale`.
+    ///
+    /// Returns `None` if the language has no translations, or its `i18n-*` feature is not enabled.
+    pub fn bundled(locale: LanguageIdentifier) -> Option<Self> {
+        let source = super::locales::source(locale.language.as_str())?;
+        Some(Self::from_ftl(locale, source).expect("bundled translations are valid"))
+    }
+
+    /// Creates a handler from an existing bundle.
+    pub fn from_bundle(bundle: FluentBundle<FluentResource>) -> Self {
+        Self { bundle }
+    }
+
+    /// Adds the messages in `source`, which must be valid Fluent syntax.
+    ///
+    /// Messages which are already in the bundle are not overridden, and result in an error.
+    pub fn add_ftl(&mut self, source: impl Into<String>) -> Result<(), FluentI18nError> {
+        let resource = FluentResource::try_new(source.into())
+            .map_err(|(_, errors)| FluentI18nError::Parse(errors))?;
+        self.bundle
+            .add_resource(resource)
+            .map_err(FluentI18nError::Resource)
+    }
+
+    /// Returns the underlying bundle.
+    pub fn bundle(&self) -> &FluentBundle<FluentResource> {
+        &self.bundle
+    }
+
+    /// Returns the underlying bundle, e.g. to register functions.
+    pub fn bundle_mut(&mut self) -> &mut FluentBundle<FluentResource> {
+        &mut self.bundle
+    }
+
+    /// Returns `None` if there is no message called `id`, or it could not be formatted,
+    /// e.g. because it refers to a variable which is not in `args`.
+    fn format(&self, id: &str, args: &FluentArgs) -> Option<Cow<'static, str>> {
+        let pattern = self.bundle.get_message(id)?.value()?;
+        let mut errors = Vec::new();
+        let message = self.bundle.format_pattern(pattern, Some(args), &mut errors);
+        if !errors.is_empty() {
+            return None;
+        }
+        Some(Cow::Owned(message.into_owned()))
+    }
+}
+
+/// Pass a value such as a `range` bound as a Fluent number if it is one,
+/// so that messages can select on it and it is formatted as a number.
+fn number(value: &dyn Display) -> FluentValue<'static> {
+    let value = value.to_string();
+    match value.parse::<FluentNumber>() {
+        Ok(number) => number.into(),
+        Err(_) => value.into(),
+    }
+}
+
+impl Debug for FluentI18n {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FluentI18n")
+            .field("locales", &self.bundle.locales)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An error which occurred while loading Fluent messages into a [`FluentI18n`].
+#[derive(Debug)]
+pub enum FluentI18nError {
+    /// The source is not valid Fluent syntax.
+    Parse(Vec<ParserError>),
+    /// The source could not be added to the bundle, e.g. because it overrides an existing message.
+    Resource(Vec<FluentError>),
+}
+
+impl Display for FluentI18nError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let errors: Vec<String> = match self {
+            FluentI18nError::Parse(errors) => errors.iter().map(|e| e.to_string()).collect(),
+            FluentI18nError::Resource(errors) => errors.iter().map(|e| e.to_string()).collect(),
+        };
+        write!(f, "failed to load fluent messages: {}", errors.join(", "))
+    }
+}
+
+impl std::error::Error for FluentI18nError {}
+
+macro_rules! fluent_messages {
+    ($($method:ident($($arg:ident: $ty:ty => $value:expr),*);)*) => {
+        impl I18n for FluentI18n {
+            $(
+                fn $method(&self, $($arg: $ty),*) -> Cow<'static, str> {
+                    #[allow(unused_mut)]
+                    let mut args = FluentArgs::new();
+                    $(args.set(stringify!($arg), $value);)*
+                    self.format(stringify!($method), &args)
+                        .unwrap_or_else(|| DefaultI18n.$method($($arg),*))
+                }
+            )*
+
+            fn custom(&self, key: &str, params: &Params) -> Option<Cow<'static, str>> {
+                let mut args = FluentArgs::new();
+                for (name, value) in params.iter() {
+                    match value {
+                        Value::Int(v) => match i64::try_from(*v) {
+                            Ok(v) => args.set(name.to_owned(), v),
+                            Err(_) => args.set(name.to_owned(), v.to_string()),
+                        },
+                        Value::Float(v) => args.set(name.to_owned(), *v),
+                        Value::Str(v) => args.set(name.to_owned(), v.to_string()),
+                    }
+                }
+                self.format(key, &args)
+            }
+
+            fn label(&self, field: &str) -> Option<Cow<'static, str>> {
+                self.format(&format!("label_{field}"), &FluentArgs::new())
+            }
+
+            fn required_if_not_set(&self) -> Cow<'static, str> {
+                self.format("required_if_not_set", &FluentArgs::new())
+                    .unwrap_or_else(|| self.required_not_set())
+            }
+        }
+    };
+}
+
+fluent_messages! {
+    length_lower_than(min: usize => min);
+    length_greater_than(max: usize => max);
+    range_lower_than(min: &dyn Display => number(min));
+    range_greater_than(max: &dyn Display => number(max));
+    credit_card_invalid(reason: InvalidCreditCard => reason.as_str());
+    pattern_no_match(pattern: &dyn Display => pattern.to_string());
+    contains_missing(pattern: &dyn Display => pattern.to_string());
+    url_invalid(reason: InvalidUrl => reason.as_str());
+    prefix_missing(pattern: &dyn Display => pattern.to_string());
+    suffix_missing(pattern: &dyn Display => pattern.to_string());
+    phone_number_invalid(reason: InvalidPhoneNumber => reason.as_str());
+    ip_invalid(kind: IpKind => kind.as_str());
+    matches_field_mismatch(field: &dyn Display => field.to_string());
+    not_equal_field_match(field: &dyn Display => field.to_string());
+    compare_not_greater_than(field: &dyn Display => field.to_string());
+    compare_not_greater_than_or_equal(field: &dyn Display => field.to_string());
+    compare_not_less_than(field: &dyn Display => field.to_string());
+    compare_not_less_than_or_equal(field: &dyn Display => field.to_string());
+    email_invalid(reason: InvalidEmail => reason.as_str());
+    ascii_invalid();
+    alphanumeric_invalid();
+    required_not_set();
+    required_with_not_set(field: &dyn Display => field.to_string());
+    required_without_not_set(field: &dyn Display => field.to_string());
+}
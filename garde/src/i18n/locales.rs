@@ -0,0 +1,47 @@
+//! Bundled Fluent translations of garde's error messages.
+//!
+//! Each locale is enabled by its own feature, e.g. `i18n-de`, and covers every [`I18n`][`super::I18n`] method.
+//! Use [`FluentI18n::bundled`][`super::FluentI18n::bundled`] to load one, or add the source to your own bundle.
+
+/// German translations, enabled by the `i18n-de` feature.
+#[cfg(feature = "i18n-de")]
+pub const DE: &str = include_str!("locales/de.ftl");
+
+/// French translations, enabled by the `i18n-fr` feature.
+#[cfg(feature = "i18n-fr")]
+pub const FR: &str = include_str!("locales/fr.ftl");
+
+/// Spanish translations, enabled by the `i18n-es` feature.
+#[cfg(feature = "i18n-es")]
+pub const ES: &str = include_str!("locales/es.ftl");
+
+/// Czech translations, enabled by the `i18n-cs` feature.
+#[cfg(feature = "i18n-cs")]
+pub const CS: &str = include_str!("locales/cs.ftl");
+
+/// Portuguese translations, enabled by the `i18n-pt` feature.
+#[cfg(feature = "i18n-pt")]
+pub const PT: &str = include_str!("locales/pt.ftl");
+
+/// Japanese translations, enabled by the `i18n-ja` feature.
+#[cfg(feature = "i18n-ja")]
+pub const JA: &str = include_str!("locales/ja.ftl");
+
+/// Returns the bundled translations for `language`, e.g. `"de"`, if its feature is enabled.
+pub fn source(language: &str) -> Option<&'static str> {
+    match language {
+        #[cfg(feature = "i18n-de")]
+        "de" => Some(DE),
+        #[cfg(feature = "i18n-fr")]
+        "fr" => Some(FR),
+        #[cfg(feature = "i18n-es")]
+        "es" => Some(ES),
+        #[cfg(feature = "i18n-cs")]
+        "cs" => Some(CS),
+        #[cfg(feature = "i18n-pt")]
+        "pt" => Some(PT),
+        #[cfg(feature = "i18n-ja")]
+        "ja" => Some(JA),
+        _ => None,
+    }
+}
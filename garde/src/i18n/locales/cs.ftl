@@ -0,0 +1,64 @@
+length_lower_than = délka je menší než { $min }
+length_greater_than = délka je větší než { $max }
+range_lower_than = menší než { $min }
+range_greater_than = větší než { $max }
+credit_card_invalid = neplatné číslo platební karty: { $reason ->
+    [invalid_format] neplatný formát
+    [invalid_length] neplatná délka
+    [invalid_luhn] neplatný kontrolní součet (Luhn)
+    [unknown_type] neznámý typ karty
+   *[other] neplatná platební karta
+}
+pattern_no_match = neodpovídá vzoru /{ $pattern }/
+contains_missing = neobsahuje „{ $pattern }“
+url_invalid = neplatná URL: { $reason ->
+    [empty_host] prázdný host
+    [idna_error] neplatný mezinárodní název domény
+    [invalid_port] neplatné číslo portu
+    [invalid_ipv4_address] neplatná IPv4 adresa
+    [invalid_ipv6_address] neplatná IPv6 adresa
+    [invalid_domain_character] neplatný znak v doméně
+    [relative_url_without_base] relativní URL bez základní URL
+    [relative_url_with_cannot_be_a_base_base] relativní URL se základem, který nemůže být základem
+    [set_host_on_cannot_be_a_base_url] URL, která nemůže být základem, nemá host, který by šlo nastavit
+    [overflow] URL delší než 4 GB nejsou podporovány
+   *[other] neplatná URL
+}
+prefix_missing = nezačíná na „{ $pattern }“
+suffix_missing = nekončí na „{ $pattern }“
+phone_number_invalid = { $reason ->
+    [invalid] neplatné telefonní číslo
+   *[other] neplatné telefonní číslo: { $reason ->
+        [not_a_number] není číslo
+        [invalid_country_code] neplatný kód země
+        [too_short_after_idd] číslo je po mezinárodní předvolbě příliš krátké
+        [too_short_nsn] číslo je po kódu země příliš krátké
+        [too_long] číslo je příliš dlouhé
+        [malformed_integer] chybná číselná část telefonního čísla
+       *[other] neplatné telefonní číslo
+    }
+}
+ip_invalid = neplatná { $kind ->
+    [ipv4] IPv4
+    [ipv6] IPv6
+   *[ip] IP
+} adresa
+matches_field_mismatch = neodpovídá poli { $field }
+not_equal_field_match = shoduje se s polem { $field }
+compare_not_greater_than = není větší než pole { $field }
+compare_not_greater_than_or_equal = není větší nebo rovno poli { $field }
+compare_not_less_than = není menší než pole { $field }
+compare_not_less_than_or_equal = není menší nebo rovno poli { $field }
+email_invalid = neplatný e-mail: { $reason ->
+    [empty] hodnota je prázdná
+    [missing_at] chybí „@“
+    [user_length_exceeded] uživatelské jméno přesahuje maximální délku 64 znaků
+    [invalid_user] uživatelské jméno obsahuje neočekávané znaky
+    [domain_length_exceeded] doména přesahuje maximální délku 255 znaků
+   *[invalid_domain] doména obsahuje neočekávané znaky
+}
+ascii_invalid = není ASCII
+alphanumeric_invalid = není alfanumerické
+required_not_set = není vyplněno
+required_with_not_set = není vyplněno, přestože pole { $field } vyplněno je
+required_without_not_set = není vyplněno, a pole { $field } také ne
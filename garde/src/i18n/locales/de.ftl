@@ -0,0 +1,64 @@
+length_lower_than = Länge ist kleiner als { $min }
+length_greater_than = Länge ist größer als { $max }
+range_lower_than = kleiner als { $min }
+range_greater_than = größer als { $max }
+credit_card_invalid = keine gültige Kreditkartennummer: { $reason ->
+    [invalid_format] ungültiges Format
+    [invalid_length] ungültige Länge
+    [invalid_luhn] ungültige Luhn-Prüfsumme
+    [unknown_type] unbekannter Kartentyp
+   *[other] ungültige Kreditkarte
+}
+pattern_no_match = entspricht nicht dem Muster /{ $pattern }/
+contains_missing = enthält nicht „{ $pattern }“
+url_invalid = keine gültige URL: { $reason ->
+    [empty_host] leerer Host
+    [idna_error] ungültiger internationaler Domainname
+    [invalid_port] ungültige Portnummer
+    [invalid_ipv4_address] ungültige IPv4-Adresse
+    [invalid_ipv6_address] ungültige IPv6-Adresse
+    [invalid_domain_character] ungültiges Zeichen in der Domain
+    [relative_url_without_base] relative URL ohne Basis-URL
+    [relative_url_with_cannot_be_a_base_base] relative URL mit einer Basis-URL, die keine Basis sein kann
+    [set_host_on_cannot_be_a_base_url] eine URL, die keine Basis sein kann, hat keinen Host
+    [overflow] URLs über 4 GB werden nicht unterstützt
+   *[other] ungültige URL
+}
+prefix_missing = beginnt nicht mit „{ $pattern }“
+suffix_missing = endet nicht mit „{ $pattern }“
+phone_number_invalid = { $reason ->
+    [invalid] keine gültige Telefonnummer
+   *[other] keine gültige Telefonnummer: { $reason ->
+        [not_a_number] keine Zahl
+        [invalid_country_code] ungültige Ländervorwahl
+        [too_short_after_idd] die Nummer ist nach der internationalen Vorwahl zu kurz
+        [too_short_nsn] die Nummer ist nach der Ländervorwahl zu kurz
+        [too_long] die Nummer ist zu lang
+        [malformed_integer] fehlerhafter Zahlenteil in der Telefonnummer
+       *[other] ungültige Telefonnummer
+    }
+}
+ip_invalid = keine gültige { $kind ->
+    [ipv4] IPv4
+    [ipv6] IPv6
+   *[ip] IP
+}-Adresse
+matches_field_mismatch = stimmt nicht mit dem Feld { $field } überein
+not_equal_field_match = stimmt mit dem Feld { $field } überein
+compare_not_greater_than = nicht größer als das Feld { $field }
+compare_not_greater_than_or_equal = nicht größer als oder gleich dem Feld { $field }
+compare_not_less_than = nicht kleiner als das Feld { $field }
+compare_not_less_than_or_equal = nicht kleiner als oder gleich dem Feld { $field }
+email_invalid = keine gültige E-Mail-Adresse: { $reason ->
+    [empty] Wert ist leer
+    [missing_at] „@“ fehlt
+    [user_length_exceeded] Benutzername überschreitet die maximale Länge von 64 Zeichen
+    [invalid_user] Benutzername enthält unerwartete Zeichen
+    [domain_length_exceeded] Domain überschreitet die maximale Länge von 255 Zeichen
+   *[invalid_domain] Domain enthält unerwartete Zeichen
+}
+ascii_invalid = nicht ASCII
+alphanumeric_invalid = nicht alphanumerisch
+required_not_set = nicht gesetzt
+required_with_not_set = nicht gesetzt, obwohl das Feld { $field } gesetzt ist
+required_without_not_set = nicht gesetzt, und das Feld { $field } ist auch nicht gesetzt
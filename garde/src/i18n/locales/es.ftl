@@ -0,0 +1,64 @@
+length_lower_than = la longitud es menor que { $min }
+length_greater_than = la longitud es mayor que { $max }
+range_lower_than = menor que { $min }
+range_greater_than = mayor que { $max }
+credit_card_invalid = número de tarjeta de crédito no válido: { $reason ->
+    [invalid_format] formato no válido
+    [invalid_length] longitud no válida
+    [invalid_luhn] suma de verificación de Luhn no válida
+    [unknown_type] tipo de tarjeta desconocido
+   *[other] tarjeta de crédito no válida
+}
+pattern_no_match = no coincide con el patrón /{ $pattern }/
+contains_missing = no contiene «{ $pattern }»
+url_invalid = URL no válida: { $reason ->
+    [empty_host] host vacío
+    [idna_error] nombre de dominio internacional no válido
+    [invalid_port] número de puerto no válido
+    [invalid_ipv4_address] dirección IPv4 no válida
+    [invalid_ipv6_address] dirección IPv6 no válida
+    [invalid_domain_character] carácter no válido en el dominio
+    [relative_url_without_base] URL relativa sin URL base
+    [relative_url_with_cannot_be_a_base_base] URL relativa con una base que no puede ser base
+    [set_host_on_cannot_be_a_base_url] una URL que no puede ser base no tiene host que establecer
+    [overflow] no se admiten URL de más de 4 GB
+   *[other] URL no válida
+}
+prefix_missing = no empieza por «{ $pattern }»
+suffix_missing = no termina en «{ $pattern }»
+phone_number_invalid = { $reason ->
+    [invalid] número de teléfono no válido
+   *[other] número de teléfono no válido: { $reason ->
+        [not_a_number] no es un número
+        [invalid_country_code] código de país no válido
+        [too_short_after_idd] el número es demasiado corto después del prefijo internacional
+        [too_short_nsn] el número es demasiado corto después del código de país
+        [too_long] el número es demasiado largo
+        [malformed_integer] parte numérica mal formada en el número de teléfono
+       *[other] número de teléfono no válido
+    }
+}
+ip_invalid = dirección { $kind ->
+    [ipv4] IPv4
+    [ipv6] IPv6
+   *[ip] IP
+} no válida
+matches_field_mismatch = no coincide con el campo { $field }
+not_equal_field_match = coincide con el campo { $field }
+compare_not_greater_than = no es mayor que el campo { $field }
+compare_not_greater_than_or_equal = no es mayor o igual que el campo { $field }
+compare_not_less_than = no es menor que el campo { $field }
+compare_not_less_than_or_equal = no es menor o igual que el campo { $field }
+email_invalid = correo electrónico no válido: { $reason ->
+    [empty] el valor está vacío
+    [missing_at] falta «@»
+    [user_length_exceeded] el usuario supera la longitud máxima de 64 caracteres
+    [invalid_user] el usuario contiene caracteres inesperados
+    [domain_length_exceeded] el dominio supera la longitud máxima de 255 caracteres
+   *[invalid_domain] el dominio contiene caracteres inesperados
+}
+ascii_invalid = no es ASCII
+alphanumeric_invalid = no es alfanumérico
+required_not_set = no establecido
+required_with_not_set = no establecido, pero el campo { $field } sí lo está
+required_without_not_set = no establecido, y el campo { $field } tampoco
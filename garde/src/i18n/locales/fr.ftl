@@ -0,0 +1,64 @@
+length_lower_than = la longueur est inférieure à { $min }
+length_greater_than = la longueur est supérieure à { $max }
+range_lower_than = inférieur à { $min }
+range_greater_than = supérieur à { $max }
+credit_card_invalid = numéro de carte bancaire invalide : { $reason ->
+    [invalid_format] format invalide
+    [invalid_length] longueur invalide
+    [invalid_luhn] somme de contrôle de Luhn invalide
+    [unknown_type] type de carte inconnu
+   *[other] carte bancaire invalide
+}
+pattern_no_match = ne correspond pas au motif /{ $pattern }/
+contains_missing = ne contient pas « { $pattern } »
+url_invalid = URL invalide : { $reason ->
+    [empty_host] hôte vide
+    [idna_error] nom de domaine international invalide
+    [invalid_port] numéro de port invalide
+    [invalid_ipv4_address] adresse IPv4 invalide
+    [invalid_ipv6_address] adresse IPv6 invalide
+    [invalid_domain_character] caractère invalide dans le domaine
+    [relative_url_without_base] URL relative sans URL de base
+    [relative_url_with_cannot_be_a_base_base] URL relative avec une base qui ne peut pas servir de base
+    [set_host_on_cannot_be_a_base_url] une URL qui ne peut pas servir de base n’a pas d’hôte à définir
+    [overflow] les URL de plus de 4 Go ne sont pas prises en charge
+   *[other] URL invalide
+}
+prefix_missing = ne commence pas par « { $pattern } »
+suffix_missing = ne se termine pas par « { $pattern } »
+phone_number_invalid = { $reason ->
+    [invalid] numéro de téléphone invalide
+   *[other] numéro de téléphone invalide : { $reason ->
+        [not_a_number] ce n’est pas un nombre
+        [invalid_country_code] indicatif de pays invalide
+        [too_short_after_idd] le numéro est trop court après le préfixe international
+        [too_short_nsn] le numéro est trop court après l’indicatif de pays
+        [too_long] le numéro est trop long
+        [malformed_integer] partie numérique mal formée dans le numéro de téléphone
+       *[other] numéro de téléphone invalide
+    }
+}
+ip_invalid = adresse { $kind ->
+    [ipv4] IPv4
+    [ipv6] IPv6
+   *[ip] IP
+} invalide
+matches_field_mismatch = ne correspond pas au champ { $field }
+not_equal_field_match = correspond au champ { $field }
+compare_not_greater_than = n’est pas supérieur au champ { $field }
+compare_not_greater_than_or_equal = n’est pas supérieur ou égal au champ { $field }
+compare_not_less_than = n’est pas inférieur au champ { $field }
+compare_not_less_than_or_equal = n’est pas inférieur ou égal au champ { $field }
+email_invalid = adresse e-mail invalide : { $reason ->
+    [empty] la valeur est vide
+    [missing_at] il manque « @ »
+    [user_length_exceeded] le nom d’utilisateur dépasse la longueur maximale de 64 caractères
+    [invalid_user] le nom d’utilisateur contient des caractères inattendus
+    [domain_length_exceeded] le domaine dépasse la longueur maximale de 255 caractères
+   *[invalid_domain] le domaine contient des caractères inattendus
+}
+ascii_invalid = n’est pas ASCII
+alphanumeric_invalid = n’est pas alphanumérique
+required_not_set = non renseigné
+required_with_not_set = non renseigné, alors que le champ { $field } l’est
+required_without_not_set = non renseigné, et le champ { $field } non plus
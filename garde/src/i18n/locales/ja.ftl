@@ -0,0 +1,64 @@
+length_lower_than = 長さが { $min } 未満です
+length_greater_than = 長さが { $max } を超えています
+range_lower_than = { $min } 未満です
+range_greater_than = { $max } を超えています
+credit_card_invalid = 有効なクレジットカード番号ではありません（{ $reason ->
+    [invalid_format] 形式が不正です
+    [invalid_length] 長さが不正です
+    [invalid_luhn] Luhn チェックサムが不正です
+    [unknown_type] カードの種類が不明です
+   *[other] 無効なクレジットカードです
+}）
+pattern_no_match = パターン /{ $pattern }/ に一致しません
+contains_missing = 「{ $pattern }」が含まれていません
+url_invalid = 有効な URL ではありません（{ $reason ->
+    [empty_host] ホストが空です
+    [idna_error] 国際化ドメイン名が不正です
+    [invalid_port] ポート番号が不正です
+    [invalid_ipv4_address] IPv4 アドレスが不正です
+    [invalid_ipv6_address] IPv6 アドレスが不正です
+    [invalid_domain_character] ドメインに不正な文字が含まれています
+    [relative_url_without_base] ベース URL のない相対 URL です
+    [relative_url_with_cannot_be_a_base_base] ベースにできない URL を基準とする相対 URL です
+    [set_host_on_cannot_be_a_base_url] ベースにできない URL には設定できるホストがありません
+    [overflow] 4 GB を超える URL はサポートされていません
+   *[other] 無効な URL です
+}）
+prefix_missing = 「{ $pattern }」で始まっていません
+suffix_missing = 「{ $pattern }」で終わっていません
+phone_number_invalid = { $reason ->
+    [invalid] 有効な電話番号ではありません
+   *[other] 有効な電話番号ではありません（{ $reason ->
+        [not_a_number] 数値ではありません
+        [invalid_country_code] 国番号が不正です
+        [too_short_after_idd] 国際プレフィックスの後の番号が短すぎます
+        [too_short_nsn] 国番号の後の番号が短すぎます
+        [too_long] 番号が長すぎます
+        [malformed_integer] 電話番号の数値部分が不正です
+       *[other] 無効な電話番号です
+    }）
+}
+ip_invalid = 有効な { $kind ->
+    [ipv4] IPv4
+    [ipv6] IPv6
+   *[ip] IP
+} アドレスではありません
+matches_field_mismatch = { $field } フィールドと一致しません
+not_equal_field_match = { $field } フィールドと一致しています
+compare_not_greater_than = { $field } フィールドより大きくありません
+compare_not_greater_than_or_equal = { $field } フィールド以上ではありません
+compare_not_less_than = { $field } フィールドより小さくありません
+compare_not_less_than_or_equal = { $field } フィールド以下ではありません
+email_invalid = 有効なメールアドレスではありません（{ $reason ->
+    [empty] 値が空です
+    [missing_at] 「@」がありません
+    [user_length_exceeded] ユーザー名が最大長の 64 文字を超えています
+    [invalid_user] ユーザー名に不正な文字が含まれています
+    [domain_length_exceeded] ドメインが最大長の 255 文字を超えています
+   *[invalid_domain] ドメインに不正な文字が含まれています
+}）
+ascii_invalid = ASCII ではありません
+alphanumeric_invalid = 英数字ではありません
+required_not_set = 設定されていません
+required_with_not_set = { $field } フィールドが設定されているのに、設定されていません
+required_without_not_set = 設定されておらず、{ $field } フィールドも設定されていません
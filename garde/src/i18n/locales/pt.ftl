@@ -0,0 +1,64 @@
+length_lower_than = o comprimento é menor que { $min }
+length_greater_than = o comprimento é maior que { $max }
+range_lower_than = menor que { $min }
+range_greater_than = maior que { $max }
+credit_card_invalid = número de cartão de crédito inválido: { $reason ->
+    [invalid_format] formato inválido
+    [invalid_length] comprimento inválido
+    [invalid_luhn] dígito verificador de Luhn inválido
+    [unknown_type] tipo de cartão desconhecido
+   *[other] cartão de crédito inválido
+}
+pattern_no_match = não corresponde ao padrão /{ $pattern }/
+contains_missing = não contém "{ $pattern }"
+url_invalid = URL inválida: { $reason ->
+    [empty_host] host vazio
+    [idna_error] nome de domínio internacional inválido
+    [invalid_port] número de porta inválido
+    [invalid_ipv4_address] endereço IPv4 inválido
+    [invalid_ipv6_address] endereço IPv6 inválido
+    [invalid_domain_character] caractere inválido no domínio
+    [relative_url_without_base] URL relativa sem URL base
+    [relative_url_with_cannot_be_a_base_base] URL relativa com uma base que não pode ser base
+    [set_host_on_cannot_be_a_base_url] uma URL que não pode ser base não tem host para definir
+    [overflow] URLs com mais de 4 GB não são suportadas
+   *[other] URL inválida
+}
+prefix_missing = não começa com "{ $pattern }"
+suffix_missing = não termina com "{ $pattern }"
+phone_number_invalid = { $reason ->
+    [invalid] número de telefone inválido
+   *[other] número de telefone inválido: { $reason ->
+        [not_a_number] não é um número
+        [invalid_country_code] código de país inválido
+        [too_short_after_idd] o número é curto demais após o prefixo internacional
+        [too_short_nsn] o número é curto demais após o código de país
+        [too_long] o número é longo demais
+        [malformed_integer] parte numérica malformada no número de telefone
+       *[other] número de telefone inválido
+    }
+}
+ip_invalid = endereço { $kind ->
+    [ipv4] IPv4
+    [ipv6] IPv6
+   *[ip] IP
+} inválido
+matches_field_mismatch = não corresponde ao campo { $field }
+not_equal_field_match = corresponde ao campo { $field }
+compare_not_greater_than = não é maior que o campo { $field }
+compare_not_greater_than_or_equal = não é maior ou igual ao campo { $field }
+compare_not_less_than = não é menor que o campo { $field }
+compare_not_less_than_or_equal = não é menor ou igual ao campo { $field }
+email_invalid = e-mail inválido: { $reason ->
+    [empty] o valor está vazio
+    [missing_at] falta "@"
+    [user_length_exceeded] o usuário excede o comprimento máximo de 64 caracteres
+    [invalid_user] o usuário contém caracteres inesperados
+    [domain_length_exceeded] o domínio excede o comprimento máximo de 255 caracteres
+   *[invalid_domain] o domínio contém caracteres inesperados
+}
+ascii_invalid = não é ASCII
+alphanumeric_invalid = não é alfanumérico
+required_not_set = não preenchido
+required_with_not_set = não preenchido, mas o campo { $field } está preenchido
+required_without_not_set = não preenchido, e o campo { $field } também não
@@ -0,0 +1,87 @@
+//! ## Rule introspection
+//!
+//! [`Validate::rules`][`crate::Validate::rules`] describes which rules apply to which fields of a type,
+//! which is useful for generating form metadata, documentation or admin interfaces.
+//!
+//! ```rust
+//! use garde::Validate;
+//!
+//! #[derive(garde::Validate)]
+//! struct User {
+//!     #[garde(rename = "userName", ascii, length(min = 3, max = 25))]
+//!     username: String,
+//!     #[garde(dive)]
+//!     addresses: Vec<Address>,
+//! }
+//!
+//! #[derive(garde::Validate)]
+//! struct Address {
+//!     #[garde(length(min = 1))]
+//!     street: String,
+//! }
+//!
+//! let rules = User::rules();
+//! assert_eq!(rules[1].name, "userName");
+//! assert_eq!(rules[1].rules.rules[1].name, "length::simple");
+//! assert_eq!(rules[1].rules.rules[1].arg("max"), Some("25"));
+//!
+//! let addresses = rules[0].dive.unwrap()();
+//! assert_eq!(addresses[0].name, "street");
+//! ```
+//!
+//! Arguments are stored as the source of the expression they were given as,
+//! such as `"25"` or `"ctx.max_len"`, except for literal patterns, which are stored as the pattern itself.
+
+/// The rules which apply to a single field.
+#[derive(Debug, Clone, Copy)]
+pub struct FieldRules {
+    /// The name of the field in error paths, which is its `rename`, identifier or tuple index.
+    pub name: &'static str,
+    /// The enum variant the field belongs to, or `None` for structs.
+    pub variant: Option<&'static str>,
+    /// The rules applied to the field itself.
+    pub rules: RuleSet,
+    /// The rules of the field's type, if the field uses `dive`.
+    pub dive: Option<fn() -> &'static [FieldRules]>,
+}
+
+/// A set of rules, along with nested `inner` and conditional rules.
+#[derive(Debug, Clone, Copy)]
+pub struct RuleSet {
+    /// The built-in rules.
+    pub rules: &'static [Rule],
+    /// The source of each `custom` and `custom_async` validator.
+    pub custom: &'static [&'static str],
+    /// The rules applied to each item through `inner`.
+    pub inner: Option<&'static RuleSet>,
+    /// The rules which only apply while a condition holds, from `if` or `groups`.
+    pub conditional: &'static [ConditionalRuleSet],
+}
+
+/// Rules which only apply while `condition` is `true`.
+#[derive(Debug, Clone, Copy)]
+pub struct ConditionalRuleSet {
+    /// The source of the condition.
+    pub condition: &'static str,
+    /// The rules which apply while the condition holds.
+    pub rule_set: RuleSet,
+}
+
+/// A single built-in rule.
+#[derive(Debug, Clone, Copy)]
+pub struct Rule {
+    /// The name of the rule, such as `"ascii"` or `"length::chars"`.
+    pub name: &'static str,
+    /// The arguments of the rule, such as `("min", "3")`.
+    pub args: &'static [(&'static str, &'static str)],
+}
+
+impl Rule {
+    /// Returns the argument called `name`, if the rule has one.
+    pub fn arg(&self, name: &str) -> Option<&'static str> {
+        self.args
+            .iter()
+            .find(|(arg, _)| *arg == name)
+            .map(|(_, value)| *value)
+    }
+}
@@ -0,0 +1,776 @@
+//! ## Validating JSON values
+//!
+//! [`validate`] deserializes a [`serde_json::Value`] into `T` and validates it, reporting every type error
+//! alongside every rule error in a single [`Report`], instead of stopping at the first type error.
+//!
+//! ```rust
+//! #[derive(Debug, serde::Deserialize, garde::Validate)]
+//! struct User {
+//!     #[garde(length(min = 3))]
+//!     username: String,
+//!     #[garde(range(min = 18))]
+//!     age: u8,
+//!     #[garde(email)]
+//!     email: String,
+//! }
+//!
+//! let value = serde_json::json!({ "username": "ab", "age": "old" });
+//! let report = garde::json::validate::<User>(&value).unwrap_err();
+//! let errors = report
+//!     .iter()
+//!     .map(|(path, error)| format!("{path}: {error}"))
+//!     .collect::<Vec<_>>();
+//! assert_eq!(
+//!     errors,
+//!     [
+//!         "age: invalid type: string \"old\", expected u8",
+//!         "email: missing field",
+//!         "username: length is lower than 3",
+//!     ]
+//! );
+//! ```
+//!
+//! Deserialization goes through `T`'s own [`Deserialize`] implementation, so `serde` attributes are respected.
+//! When a value has the wrong type, the error is recorded at its path and a placeholder, such as `""` or `0`,
+//! is used in its place so that the rest of the value can still be checked. Rule errors at or below such a path
+//! are left out, because they would describe the placeholder.
+//!
+//! Errors which can't be worked around, such as an unknown enum variant or a string which doesn't parse into a custom type,
+//! stop deserialization. Everything found up to that point is still reported, but rules are not run.
+//!
+//! Paths are the same as in a [`Report`] from [`Validate::validate`], so fields of enum variants are not nested under the variant's name.
+//! Deserialization errors have one of the following codes: `invalid_type`, `invalid_value`, `invalid_length`,
+//! `unknown_variant`, `unknown_field`, `missing_field`, `duplicate_field` or `deserialize`.
+
+use std::cell::RefCell;
+use std::fmt::Display;
+
+use serde::de::value::BorrowedStrDeserializer;
+use serde::de::{self, DeserializeSeed, Deserializer, Expected, Unexpected, Visitor};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::error::{Error, Path};
+use crate::{Report, Validate};
+
+/// Deserializes `value` into `T` and validates it, returning every type and rule error at once.
+///
+/// See the [module documentation][`crate::json`] for details.
+pub fn validate<'de, T>(value: &'de Value) -> Result<T, Report>
+where
+    T: Deserialize<'de> + Validate,
+    T::Context: Default,
+{
+    let ctx = T::Context::default();
+    validate_with(value, &ctx)
+}
+
+/// Deserializes `value` into `T` and validates it with `ctx`, returning every type and rule error at once.
+///
+/// See the [module documentation][`crate::json`] for details.
+pub fn validate_with<'de, T>(value: &'de Value, ctx: &T::Context) -> Result<T, Report>
+where
+    T: Deserialize<'de> + Validate,
+{
+    // `serde` gives up on a struct as soon as a field is missing, so the missing fields found
+    // by each attempt are filled in with placeholders on the next one.
+    let mut missing = Vec::new();
+    let (result, errors) = loop {
+        let state = State {
+            errors: RefCell::new(Vec::new()),
+            missing: &missing,
+            new_missing: RefCell::new(Vec::new()),
+        };
+        let result = T::deserialize(Lenient::new(value, Path::empty(), &state));
+        let new_missing = state.new_missing.into_inner();
+        if result.is_err() && new_missing.iter().any(|path| !missing.contains(path)) {
+            missing.extend(new_missing);
+            continue;
+        }
+        break (result, state.errors.into_inner());
+    };
+
+    let rule_errors = match &result {
+        Ok(value) => match value.validate_with(ctx) {
+            Ok(()) => Vec::new(),
+            Err(report) => report.into_inner(),
+        },
+        Err(_) => Vec::new(),
+    };
+    let rule_errors = rule_errors
+        .into_iter()
+        .filter(|(path, _)| !errors.iter().any(|(prefix, _)| starts_with(path, prefix)))
+        .collect::<Vec<_>>();
+
+    let mut report = Report::new();
+    for (path, error) in errors.into_iter().chain(rule_errors) {
+        report.append(path, error);
+    }
+    match result {
+        Ok(value) if report.is_empty() => Ok(value),
+        _ => Err(report),
+    }
+}
+
+fn starts_with(path: &Path, prefix: &Path) -> bool {
+    path.len() >= prefix.len()
+        && prefix
+            .__iter()
+            .rev()
+            .zip(path.__iter().rev())
+            .all(|(a, b)| a == b)
+}
+
+struct State<'a> {
+    errors: RefCell<Vec<(Path, Error)>>,
+    /// Missing fields found by earlier attempts, which are filled in with placeholders.
+    missing: &'a [Path],
+    new_missing: RefCell<Vec<Path>>,
+}
+
+#[derive(Debug)]
+enum DeError {
+    Error(Error),
+    MissingField(&'static str),
+    /// The error has already been added to the report.
+    Recorded,
+}
+
+impl DeError {
+    fn new(message: impl Display, code: &str) -> Self {
+        Self::Error(Error::new(message.to_string()).with_code(code))
+    }
+}
+
+impl Display for DeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeError::Error(error) => error.fmt(f),
+            DeError::MissingField(field) => write!(f, "missing field `{field}`"),
+            DeError::Recorded => f.write_str("invalid value"),
+        }
+    }
+}
+
+impl std::error::Error for DeError {}
+
+impl de::Error for DeError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self::new(msg, "deserialize")
+    }
+
+    fn invalid_type(unexp: Unexpected, exp: &dyn Expected) -> Self {
+        Self::new(
+            format_args!("invalid type: {unexp}, expected {exp}"),
+            "invalid_type",
+        )
+    }
+
+    fn invalid_value(unexp: Unexpected, exp: &dyn Expected) -> Self {
+        Self::new(
+            format_args!("invalid value: {unexp}, expected {exp}"),
+            "invalid_value",
+        )
+    }
+
+    fn invalid_length(len: usize, exp: &dyn Expected) -> Self {
+        Self::new(
+            format_args!("invalid length {len}, expected {exp}"),
+            "invalid_length",
+        )
+    }
+
+    fn unknown_variant(variant: &str, expected: &'static [&'static str]) -> Self {
+        Self::new(
+            format_args!("unknown variant `{variant}`, {}", one_of(expected)),
+            "unknown_variant",
+        )
+    }
+
+    fn unknown_field(field: &str, expected: &'static [&'static str]) -> Self {
+        Self::new(
+            format_args!("unknown field `{field}`, {}", one_of(expected)),
+            "unknown_field",
+        )
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        Self::MissingField(field)
+    }
+
+    fn duplicate_field(field: &'static str) -> Self {
+        Self::new(format_args!("duplicate field `{field}`"), "duplicate_field")
+    }
+}
+
+fn one_of(expected: &[&str]) -> String {
+    match expected {
+        [] => String::from("there are none"),
+        expected => {
+            let expected = expected
+                .iter()
+                .map(|name| format!("`{name}`"))
+                .collect::<Vec<_>>();
+            format!("expected one of {}", expected.join(", "))
+        }
+    }
+}
+
+fn unexpected(value: &Value) -> Unexpected<'_> {
+    match value {
+        Value::Null => Unexpected::Unit,
+        Value::Bool(v) => Unexpected::Bool(*v),
+        Value::Number(n) => match (n.as_u64(), n.as_i64(), n.as_f64()) {
+            (Some(v), _, _) => Unexpected::Unsigned(v),
+            (_, Some(v), _) => Unexpected::Signed(v),
+            (_, _, v) => Unexpected::Float(v.unwrap_or_default()),
+        },
+        Value::String(v) => Unexpected::Str(v),
+        Value::Array(_) => Unexpected::Seq,
+        Value::Object(_) => Unexpected::Map,
+    }
+}
+
+static NULL: Value = Value::Null;
+
+/// A [`Deserializer`] which records errors at their path, and uses placeholders for values of the wrong type.
+struct Lenient<'a, 'de> {
+    value: &'de Value,
+    path: Path,
+    state: &'a State<'a>,
+    /// Placeholders don't record errors, because the error they stand in for has already been recorded.
+    silent: bool,
+}
+
+impl<'a, 'de> Lenient<'a, 'de> {
+    fn new(value: &'de Value, path: Path, state: &'a State<'a>) -> Self {
+        Self {
+            value,
+            path,
+            state,
+            silent: false,
+        }
+    }
+
+    fn record(&self, path: Path, error: Error) {
+        if !self.silent {
+            self.state.errors.borrow_mut().push((path, error));
+        }
+    }
+
+    fn fail(&self, error: DeError) -> DeError {
+        match error {
+            DeError::Error(error) => self.record(self.path.clone(), error),
+            DeError::MissingField(field) => {
+                let path = self.path.join(field);
+                if !self.silent {
+                    self.state.new_missing.borrow_mut().push(path.clone());
+                }
+                self.record(path, Error::new("missing field").with_code("missing_field"));
+            }
+            DeError::Recorded => {}
+        }
+        DeError::Recorded
+    }
+
+    fn visit<V, F>(&self, visitor: V, f: F) -> Result<V::Value, DeError>
+    where
+        V: Visitor<'de>,
+        F: FnOnce(V) -> Result<V::Value, DeError>,
+    {
+        f(visitor).map_err(|error| self.fail(error))
+    }
+
+    /// Records `error` and continues with the placeholder produced by `f`.
+    fn repair<V, F>(&self, error: DeError, visitor: V, f: F) -> Result<V::Value, DeError>
+    where
+        V: Visitor<'de>,
+        F: FnOnce(V) -> Result<V::Value, DeError>,
+    {
+        self.fail(error);
+        // If the placeholder is rejected too, the error has already been recorded.
+        f(visitor).map_err(|_| DeError::Recorded)
+    }
+
+    fn mismatch<V, F>(&self, visitor: V, f: F) -> Result<V::Value, DeError>
+    where
+        V: Visitor<'de>,
+        F: FnOnce(V) -> Result<V::Value, DeError>,
+    {
+        let error = de::Error::invalid_type(unexpected(self.value), &visitor);
+        self.repair(error, visitor, f)
+    }
+
+    fn seq<I>(&self, values: I) -> Seq<'a, I>
+    where
+        I: Iterator<Item = &'de Value>,
+    {
+        Seq {
+            values,
+            index: 0,
+            path: self.path.clone(),
+            state: self.state,
+            silent: self.silent,
+        }
+    }
+
+    fn placeholder_seq(&self, len: usize) -> Seq<'a, impl Iterator<Item = &'de Value>> {
+        Seq {
+            silent: true,
+            ..self.seq(std::iter::repeat_n(&NULL, len))
+        }
+    }
+
+    fn map<I>(&self, entries: I) -> Map<'a, 'de, I>
+    where
+        I: Iterator<Item = (&'de str, &'de Value, bool)>,
+    {
+        Map {
+            entries,
+            value: None,
+            path: self.path.clone(),
+            state: self.state,
+            silent: self.silent,
+        }
+    }
+}
+
+macro_rules! deserialize_integer {
+    ($($method:ident => $visit:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+                let Value::Number(n) = self.value else {
+                    return self.mismatch(visitor, |visitor| visitor.$visit(0));
+                };
+                let v = n
+                    .as_i64()
+                    .and_then(|v| <$ty>::try_from(v).ok())
+                    .or_else(|| n.as_u64().and_then(|v| <$ty>::try_from(v).ok()));
+                match v {
+                    Some(v) => self.visit(visitor, |visitor| visitor.$visit(v)),
+                    None if n.is_f64() => self.mismatch(visitor, |visitor| visitor.$visit(0)),
+                    None => {
+                        let error = de::Error::invalid_value(unexpected(self.value), &visitor);
+                        self.repair(error, visitor, |visitor| visitor.$visit(0))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for Lenient<'_, 'de> {
+    type Error = DeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        match self.value {
+            Value::Null => self.visit(visitor, |visitor| visitor.visit_unit()),
+            Value::Bool(v) => self.visit(visitor, |visitor| visitor.visit_bool(*v)),
+            Value::Number(n) => match (n.as_u64(), n.as_i64(), n.as_f64()) {
+                (Some(v), _, _) => self.visit(visitor, |visitor| visitor.visit_u64(v)),
+                (_, Some(v), _) => self.visit(visitor, |visitor| visitor.visit_i64(v)),
+                (_, _, v) => {
+                    self.visit(visitor, |visitor| visitor.visit_f64(v.unwrap_or_default()))
+                }
+            },
+            Value::String(v) => self.visit(visitor, |visitor| visitor.visit_borrowed_str(v)),
+            Value::Array(values) => {
+                let seq = self.seq(values.iter());
+                self.visit(visitor, |visitor| visitor.visit_seq(seq))
+            }
+            Value::Object(entries) => {
+                let map = self.map(entries.iter().map(|(k, v)| (k.as_str(), v, false)));
+                self.visit(visitor, |visitor| visitor.visit_map(map))
+            }
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        match self.value {
+            Value::Bool(v) => self.visit(visitor, |visitor| visitor.visit_bool(*v)),
+            _ => self.mismatch(visitor, |visitor| visitor.visit_bool(false)),
+        }
+    }
+
+    deserialize_integer! {
+        deserialize_i8 => visit_i8(i8),
+        deserialize_i16 => visit_i16(i16),
+        deserialize_i32 => visit_i32(i32),
+        deserialize_i64 => visit_i64(i64),
+        deserialize_i128 => visit_i128(i128),
+        deserialize_u8 => visit_u8(u8),
+        deserialize_u16 => visit_u16(u16),
+        deserialize_u32 => visit_u32(u32),
+        deserialize_u64 => visit_u64(u64),
+        deserialize_u128 => visit_u128(u128),
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        self.deserialize_f64(visitor)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        match self.value.as_f64() {
+            Some(v) => self.visit(visitor, |visitor| visitor.visit_f64(v)),
+            None => self.mismatch(visitor, |visitor| visitor.visit_f64(0.0)),
+        }
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        match self.value {
+            Value::String(v) => self.visit(visitor, |visitor| visitor.visit_borrowed_str(v)),
+            _ => self.mismatch(visitor, |visitor| visitor.visit_char(' ')),
+        }
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        match self.value {
+            Value::String(v) => self.visit(visitor, |visitor| visitor.visit_borrowed_str(v)),
+            _ => self.mismatch(visitor, |visitor| visitor.visit_borrowed_str("")),
+        }
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        match self.value {
+            Value::Null => self.visit(visitor, |visitor| visitor.visit_none()),
+            _ => {
+                let some = Lenient {
+                    path: self.path.clone(),
+                    ..self
+                };
+                self.visit(visitor, |visitor| visitor.visit_some(some))
+            }
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        match self.value {
+            Value::Null => self.visit(visitor, |visitor| visitor.visit_unit()),
+            _ => self.mismatch(visitor, |visitor| visitor.visit_unit()),
+        }
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        let inner = Lenient {
+            path: self.path.clone(),
+            ..self
+        };
+        self.visit(visitor, |visitor| visitor.visit_newtype_struct(inner))
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        match self.value {
+            Value::Array(values) => {
+                let seq = self.seq(values.iter());
+                self.visit(visitor, |visitor| visitor.visit_seq(seq))
+            }
+            _ => {
+                let seq = self.placeholder_seq(0);
+                self.mismatch(visitor, |visitor| visitor.visit_seq(seq))
+            }
+        }
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        match self.value {
+            Value::Array(values) => {
+                let seq = self.seq(values.iter());
+                self.visit(visitor, |visitor| visitor.visit_seq(seq))
+            }
+            _ => {
+                let seq = self.placeholder_seq(len);
+                self.mismatch(visitor, |visitor| visitor.visit_seq(seq))
+            }
+        }
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        match self.value {
+            Value::Object(entries) => {
+                let map = self.map(entries.iter().map(|(k, v)| (k.as_str(), v, false)));
+                self.visit(visitor, |visitor| visitor.visit_map(map))
+            }
+            _ => {
+                let map = self.map(std::iter::empty());
+                self.mismatch(visitor, |visitor| visitor.visit_map(map))
+            }
+        }
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        let Value::Object(entries) = self.value else {
+            let map = Map {
+                silent: true,
+                ..self.map(fields.iter().map(|field| (*field, &NULL, true)))
+            };
+            return self.mismatch(visitor, |visitor| visitor.visit_map(map));
+        };
+        let missing = fields
+            .iter()
+            .filter(|field| !entries.contains_key(**field))
+            .filter(|field| self.state.missing.contains(&self.path.join(**field)))
+            .copied()
+            .collect::<Vec<_>>();
+        let entries = entries
+            .iter()
+            .map(|(k, v)| (k.as_str(), v, false))
+            .chain(missing.iter().map(|field| (*field, &NULL, true)));
+        let map = self.map(entries);
+        let result = self.visit(visitor, |visitor| visitor.visit_map(map));
+        for field in missing {
+            self.record(
+                self.path.join(field),
+                Error::new("missing field").with_code("missing_field"),
+            );
+        }
+        result
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        let (variant, value) = match self.value {
+            Value::String(variant) => (variant.as_str(), None),
+            Value::Object(entries) if entries.len() == 1 => {
+                let (variant, value) = entries.iter().next().unwrap();
+                (variant.as_str(), Some(value))
+            }
+            _ => {
+                let Some(variant) = variants.first() else {
+                    let error = de::Error::invalid_type(unexpected(self.value), &visitor);
+                    return Err(self.fail(error));
+                };
+                let placeholder = Enum {
+                    variant,
+                    value: Some(&NULL),
+                    path: self.path.clone(),
+                    state: self.state,
+                    silent: true,
+                };
+                return self.mismatch(visitor, |visitor| visitor.visit_enum(placeholder));
+            }
+        };
+        let access = Enum {
+            variant,
+            value,
+            path: self.path.clone(),
+            state: self.state,
+            silent: self.silent,
+        };
+        self.visit(visitor, |visitor| visitor.visit_enum(access))
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        self.visit(visitor, |visitor| visitor.visit_unit())
+    }
+}
+
+struct Seq<'a, I> {
+    values: I,
+    index: usize,
+    path: Path,
+    state: &'a State<'a>,
+    silent: bool,
+}
+
+impl<'de, I> de::SeqAccess<'de> for Seq<'_, I>
+where
+    I: Iterator<Item = &'de Value>,
+{
+    type Error = DeError;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, DeError>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        let Some(value) = self.values.next() else {
+            return Ok(None);
+        };
+        let path = self.path.join(self.index);
+        self.index += 1;
+        seed.deserialize(Lenient {
+            value,
+            path,
+            state: self.state,
+            silent: self.silent,
+        })
+        .map(Some)
+    }
+}
+
+struct Map<'a, 'de, I> {
+    entries: I,
+    /// The value of the last key, along with whether it is a placeholder.
+    value: Option<(&'de str, &'de Value, bool)>,
+    path: Path,
+    state: &'a State<'a>,
+    silent: bool,
+}
+
+impl<'de, I> de::MapAccess<'de> for Map<'_, 'de, I>
+where
+    I: Iterator<Item = (&'de str, &'de Value, bool)>,
+{
+    type Error = DeError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, DeError>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        let Some((key, value, is_placeholder)) = self.entries.next() else {
+            return Ok(None);
+        };
+        self.value = Some((key, value, is_placeholder));
+        seed.deserialize(BorrowedStrDeserializer::new(key))
+            .map(Some)
+            .map_err(|error| {
+                Lenient {
+                    value,
+                    path: self.path.join(key),
+                    state: self.state,
+                    silent: self.silent,
+                }
+                .fail(error)
+            })
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, DeError>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let (key, value, is_placeholder) = self
+            .value
+            .take()
+            .expect("`next_value_seed` called before `next_key_seed`");
+        seed.deserialize(Lenient {
+            value,
+            path: self.path.join(key),
+            state: self.state,
+            silent: self.silent || is_placeholder,
+        })
+    }
+}
+
+struct Enum<'a, 'de> {
+    variant: &'de str,
+    value: Option<&'de Value>,
+    path: Path,
+    state: &'a State<'a>,
+    silent: bool,
+}
+
+impl<'a, 'de> Enum<'a, 'de> {
+    fn content(self) -> Lenient<'a, 'de> {
+        Lenient {
+            value: self.value.unwrap_or(&NULL),
+            path: self.path,
+            state: self.state,
+            silent: self.silent,
+        }
+    }
+}
+
+impl<'a, 'de> de::EnumAccess<'de> for Enum<'a, 'de> {
+    type Error = DeError;
+    type Variant = Self;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self), DeError>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        match seed.deserialize(BorrowedStrDeserializer::new(self.variant)) {
+            Ok(variant) => Ok((variant, self)),
+            Err(error) => Err(Lenient {
+                value: self.value.unwrap_or(&NULL),
+                path: self.path,
+                state: self.state,
+                silent: self.silent,
+            }
+            .fail(error)),
+        }
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for Enum<'_, 'de> {
+    type Error = DeError;
+
+    fn unit_variant(self) -> Result<(), DeError> {
+        match self.value {
+            None | Some(Value::Null) => Ok(()),
+            Some(value) => {
+                let content = self.content();
+                let error = de::Error::invalid_type(unexpected(value), &"unit variant");
+                content.fail(error);
+                Ok(())
+            }
+        }
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, DeError>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        seed.deserialize(self.content())
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, DeError> {
+        self.content().deserialize_tuple(len, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        self.content().deserialize_struct("", fields, visitor)
+    }
+}
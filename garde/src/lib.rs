@@ -3,20 +3,37 @@
 #[macro_use]
 pub mod i18n;
 
+pub mod async_validate;
 pub mod error;
+pub mod group;
+#[cfg(feature = "serde")]
+pub mod http;
+pub mod introspect;
+#[cfg(feature = "json")]
+pub mod json;
 pub mod rules;
+pub mod sanitize;
+#[cfg(feature = "json-schema")]
+pub mod schema;
 pub mod validate;
+#[cfg(feature = "zod")]
+pub mod zod;
 
+pub use async_validate::AsyncValidate;
 pub use error::{Error, Path, Report};
 #[cfg(feature = "derive")]
-pub use garde_derive::{select, Validate};
+pub use garde_derive::{select, AsyncValidate, Sanitize, Validate};
+pub use group::with_group;
 pub use i18n::{with_i18n, I18n};
-pub use validate::{Unvalidated, Valid, Validate};
+pub use sanitize::Sanitize;
+pub use validate::{with_partial, Unvalidated, Valid, Validate};
 
 pub type Result = ::core::result::Result<(), Error>;
 
 pub mod external {
     pub use compact_str::ToCompactString;
+    #[cfg(feature = "fluent")]
+    pub use {fluent_bundle, unic_langid};
 }
 
 #[doc(hidden)]
@@ -17,11 +17,15 @@ use crate::error::Error;
 
 pub fn apply<T: Alphanumeric>(v: &T, _: ()) -> Result<(), Error> {
     if !v.validate_alphanumeric() {
-        return Err(Error::new(i18n!(alphanumeric_invalid)));
+        return Err(i18n_error!(alphanumeric_invalid));
     }
     Ok(())
 }
 
+pub fn is_valid<T: Alphanumeric>(v: &T, _: ()) -> bool {
+    v.validate_alphanumeric()
+}
+
 pub trait Alphanumeric {
     fn validate_alphanumeric(&self) -> bool;
 }
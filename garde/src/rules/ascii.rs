@@ -17,11 +17,15 @@ use crate::error::Error;
 
 pub fn apply<T: Ascii>(v: &T, _: ()) -> Result<(), Error> {
     if !v.validate_ascii() {
-        return Err(Error::new(i18n!(ascii_invalid)));
+        return Err(i18n_error!(ascii_invalid));
     }
     Ok(())
 }
 
+pub fn is_valid<T: Ascii>(v: &T, _: ()) -> bool {
+    v.validate_ascii()
+}
+
 pub trait Ascii {
     fn validate_ascii(&self) -> bool;
 }
@@ -0,0 +1,141 @@
+//! Field ordering validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(skip)]
+//!     start: u32,
+//!     #[garde(greater_than(start))]
+//!     end: u32,
+//! }
+//! ```
+//!
+//! This module backs the `greater_than`, `greater_than_or_equal`, `less_than` and `less_than_or_equal` rules.
+//!
+//! The entrypoint is the [`Compare`] trait. Implementing this trait for a type allows that type to be used with these rules.
+//!
+//! This trait is implemented for primitive numbers, `char`, `bool`, strings, and the `std::time` types.
+//! For any other `PartialOrd` type, an implementation is a single call to [`Comparison::holds`]:
+//!
+//! ```rust
+//! use garde::rules::compare::{Compare, Comparison};
+//!
+//! #[derive(PartialEq, PartialOrd)]
+//! struct Version(u32, u32);
+//!
+//! impl Compare for Version {
+//!     fn validate_compare(&self, comparison: Comparison, other: &Self) -> bool {
+//!         comparison.holds(self.partial_cmp(other))
+//!     }
+//! }
+//! ```
+//!
+//! An `Option` is only compared when both it and the other field are `Some`, like every other rule skips `None`.
+
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::i18n::field_label;
+use crate::Error;
+
+pub fn apply<T: Compare<O>, O>(
+    v: &T,
+    (comparison, field, value): (Comparison, &str, &O),
+) -> Result<(), Error> {
+    apply_labeled(v, (comparison, field, None, value))
+}
+
+/// Like [`apply`], but refers to the other field by its `label` in the message.
+pub fn apply_labeled<T: Compare<O>, O>(
+    v: &T,
+    (comparison, field, label, value): (Comparison, &str, Option<&str>, &O),
+) -> Result<(), Error> {
+    if !v.validate_compare(comparison, value) {
+        let field_label = field_label(field, label);
+        let error = match comparison {
+            Comparison::GreaterThan => i18n_error!(compare_not_greater_than, &field_label),
+            Comparison::GreaterThanOrEqual => {
+                i18n_error!(compare_not_greater_than_or_equal, &field_label)
+            }
+            Comparison::LessThan => i18n_error!(compare_not_less_than, &field_label),
+            Comparison::LessThanOrEqual => {
+                i18n_error!(compare_not_less_than_or_equal, &field_label)
+            }
+        };
+        return Err(error.with_field(field, label));
+    }
+    Ok(())
+}
+
+pub fn is_valid<T: Compare<O>, O>(v: &T, (comparison, _, value): (Comparison, &str, &O)) -> bool {
+    v.validate_compare(comparison, value)
+}
+
+pub trait Compare<O: ?Sized = Self> {
+    fn validate_compare(&self, comparison: Comparison, other: &O) -> bool;
+}
+
+#[derive(Clone, Copy)]
+pub enum Comparison {
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+}
+
+impl Comparison {
+    /// Returns `true` if `ordering`, the result of comparing a value to the other field, satisfies this comparison.
+    ///
+    /// Values which can't be compared, such as `NaN`, never satisfy it.
+    pub fn holds(self, ordering: Option<Ordering>) -> bool {
+        match (self, ordering) {
+            (_, None) => false,
+            (Comparison::GreaterThan, Some(ordering)) => ordering.is_gt(),
+            (Comparison::GreaterThanOrEqual, Some(ordering)) => ordering.is_ge(),
+            (Comparison::LessThan, Some(ordering)) => ordering.is_lt(),
+            (Comparison::LessThanOrEqual, Some(ordering)) => ordering.is_le(),
+        }
+    }
+}
+
+macro_rules! impl_via_partial_ord {
+    ($($T:ty),*) => {
+        $(
+            impl Compare for $T {
+                fn validate_compare(&self, comparison: Comparison, other: &Self) -> bool {
+                    comparison.holds(self.partial_cmp(other))
+                }
+            }
+        )*
+    };
+}
+
+impl_via_partial_ord!(
+    u8, u16, u32, u64, usize, u128, i8, i16, i32, i64, isize, i128, f32, f64, char, bool, str,
+    String, Duration, Instant, SystemTime
+);
+
+#[cfg(feature = "rust_decimal")]
+impl_via_partial_ord!(rust_decimal::Decimal);
+
+impl Compare for Cow<'_, str> {
+    fn validate_compare(&self, comparison: Comparison, other: &Self) -> bool {
+        comparison.holds(self.partial_cmp(other))
+    }
+}
+
+impl<T: Compare<O> + ?Sized, O: ?Sized> Compare<&O> for &T {
+    fn validate_compare(&self, comparison: Comparison, other: &&O) -> bool {
+        T::validate_compare(self, comparison, other)
+    }
+}
+
+impl<T: Compare<O>, O> Compare<Option<O>> for Option<T> {
+    fn validate_compare(&self, comparison: Comparison, other: &Option<O>) -> bool {
+        match (self, other) {
+            (Some(value), Some(other)) => value.validate_compare(comparison, other),
+            _ => true,
+        }
+    }
+}
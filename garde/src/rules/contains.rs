@@ -21,11 +21,15 @@ use crate::error::Error;
 
 pub fn apply<T: Contains>(v: &T, (pat,): (&str,)) -> Result<(), Error> {
     if !v.validate_contains(pat) {
-        return Err(Error::new(i18n!(contains_missing, &pat)));
+        return Err(i18n_error!(contains_missing, &pat).with_param("pattern", pat));
     }
     Ok(())
 }
 
+pub fn is_valid<T: Contains>(v: &T, (pat,): (&str,)) -> bool {
+    v.validate_contains(pat)
+}
+
 pub trait Contains {
     fn validate_contains(&self, pat: &str) -> bool;
 }
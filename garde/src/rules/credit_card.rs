@@ -18,11 +18,15 @@ pub use crate::i18n::InvalidCreditCard;
 
 pub fn apply<T: CreditCard>(v: &T, _: ()) -> Result<(), Error> {
     if let Err(reason) = v.validate_credit_card() {
-        return Err(Error::new(i18n!(credit_card_invalid, reason)));
+        return Err(i18n_error!(credit_card_invalid, reason).with_param("reason", reason.as_str()));
     }
     Ok(())
 }
 
+pub fn is_valid<T: CreditCard>(v: &T, _: ()) -> bool {
+    v.validate_credit_card().is_ok()
+}
+
 pub trait CreditCard {
     fn validate_credit_card(&self) -> Result<(), InvalidCreditCard>;
 }
@@ -33,11 +33,15 @@ macro_rules! init_regex {
 
 pub fn apply<T: Email>(v: &T, _: ()) -> Result<(), Error> {
     if let Err(e) = v.validate_email() {
-        return Err(Error::new(i18n!(email_invalid, e)));
+        return Err(i18n_error!(email_invalid, e).with_param("reason", e.as_str()));
     }
     Ok(())
 }
 
+pub fn is_valid<T: Email>(v: &T, _: ()) -> bool {
+    v.validate_email().is_ok()
+}
+
 pub trait Email {
     fn validate_email(&self) -> Result<(), InvalidEmail>;
 }
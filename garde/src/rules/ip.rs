@@ -19,11 +19,15 @@ use crate::error::Error;
 
 pub fn apply<T: Ip>(v: &T, (kind,): (IpKind,)) -> Result<(), Error> {
     if v.validate_ip(kind).is_err() {
-        return Err(Error::new(i18n!(ip_invalid, kind)));
+        return Err(i18n_error!(ip_invalid, kind).with_param("ip_kind", kind.as_str()));
     }
     Ok(())
 }
 
+pub fn is_valid<T: Ip>(v: &T, (kind,): (IpKind,)) -> bool {
+    v.validate_ip(kind).is_ok()
+}
+
 pub trait Ip {
     type Error: Display;
 
@@ -37,6 +41,17 @@ pub enum IpKind {
     V6,
 }
 
+impl IpKind {
+    /// A stable identifier for this kind, suitable for use as an error parameter.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IpKind::Any => "ip",
+            IpKind::V4 => "ipv4",
+            IpKind::V6 => "ipv6",
+        }
+    }
+}
+
 impl Display for IpKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
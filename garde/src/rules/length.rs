@@ -63,10 +63,14 @@ use crate::error::Error;
 
 fn check_len(len: usize, min: usize, max: usize) -> Result<(), Error> {
     if len < min {
-        Err(Error::new(i18n!(length_lower_than, min)))
+        Err(i18n_error!(length_lower_than, min).with_param("min", min))
     } else if len > max {
-        Err(Error::new(i18n!(length_greater_than, max)))
+        Err(i18n_error!(length_greater_than, max).with_param("max", max))
     } else {
         Ok(())
     }
 }
+
+fn is_valid_len(len: usize, min: usize, max: usize) -> bool {
+    (min..=max).contains(&len)
+}
@@ -8,14 +8,26 @@ pub fn apply<T: Bytes>(v: &T, (min, max): (usize, usize)) -> Result<(), Error> {
     v.validate_num_bytes(min, max)
 }
 
+pub fn is_valid<T: Bytes>(v: &T, (min, max): (usize, usize)) -> bool {
+    v.is_valid_num_bytes(min, max)
+}
+
 pub trait Bytes {
     fn validate_num_bytes(&self, min: usize, max: usize) -> Result<(), Error>;
+
+    fn is_valid_num_bytes(&self, min: usize, max: usize) -> bool {
+        self.validate_num_bytes(min, max).is_ok()
+    }
 }
 
 impl<T: HasBytes> Bytes for T {
     fn validate_num_bytes(&self, min: usize, max: usize) -> Result<(), Error> {
         super::check_len(self.num_bytes(), min, max)
     }
+
+    fn is_valid_num_bytes(&self, min: usize, max: usize) -> bool {
+        super::is_valid_len(self.num_bytes(), min, max)
+    }
 }
 
 impl<T: Bytes> Bytes for Option<T> {
@@ -25,6 +37,13 @@ impl<T: Bytes> Bytes for Option<T> {
             None => Ok(()),
         }
     }
+
+    fn is_valid_num_bytes(&self, min: usize, max: usize) -> bool {
+        match self {
+            Some(v) => v.is_valid_num_bytes(min, max),
+            None => true,
+        }
+    }
 }
 
 pub trait HasBytes {
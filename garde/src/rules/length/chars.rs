@@ -8,14 +8,26 @@ pub fn apply<T: Chars>(v: &T, (min, max): (usize, usize)) -> Result<(), Error> {
     v.validate_num_chars(min, max)
 }
 
+pub fn is_valid<T: Chars>(v: &T, (min, max): (usize, usize)) -> bool {
+    v.is_valid_num_chars(min, max)
+}
+
 pub trait Chars {
     fn validate_num_chars(&self, min: usize, max: usize) -> Result<(), Error>;
+
+    fn is_valid_num_chars(&self, min: usize, max: usize) -> bool {
+        self.validate_num_chars(min, max).is_ok()
+    }
 }
 
 impl<T: HasChars> Chars for T {
     fn validate_num_chars(&self, min: usize, max: usize) -> Result<(), Error> {
         super::check_len(self.num_chars(), min, max)
     }
+
+    fn is_valid_num_chars(&self, min: usize, max: usize) -> bool {
+        super::is_valid_len(self.num_chars(), min, max)
+    }
 }
 
 impl<T: Chars> Chars for Option<T> {
@@ -25,6 +37,13 @@ impl<T: Chars> Chars for Option<T> {
             None => Ok(()),
         }
     }
+
+    fn is_valid_num_chars(&self, min: usize, max: usize) -> bool {
+        match self {
+            Some(v) => v.is_valid_num_chars(min, max),
+            None => true,
+        }
+    }
 }
 
 pub trait HasChars {
@@ -8,14 +8,26 @@ pub fn apply<T: Graphemes>(v: &T, (min, max): (usize, usize)) -> Result<(), Erro
     v.validate_num_graphemes(min, max)
 }
 
+pub fn is_valid<T: Graphemes>(v: &T, (min, max): (usize, usize)) -> bool {
+    v.is_valid_num_graphemes(min, max)
+}
+
 pub trait Graphemes {
     fn validate_num_graphemes(&self, min: usize, max: usize) -> Result<(), Error>;
+
+    fn is_valid_num_graphemes(&self, min: usize, max: usize) -> bool {
+        self.validate_num_graphemes(min, max).is_ok()
+    }
 }
 
 impl<T: HasGraphemes> Graphemes for T {
     fn validate_num_graphemes(&self, min: usize, max: usize) -> Result<(), Error> {
         super::check_len(self.num_graphemes(), min, max)
     }
+
+    fn is_valid_num_graphemes(&self, min: usize, max: usize) -> bool {
+        super::is_valid_len(self.num_graphemes(), min, max)
+    }
 }
 
 impl<T: Graphemes> Graphemes for Option<T> {
@@ -25,6 +37,13 @@ impl<T: Graphemes> Graphemes for Option<T> {
             None => Ok(()),
         }
     }
+
+    fn is_valid_num_graphemes(&self, min: usize, max: usize) -> bool {
+        match self {
+            Some(v) => v.is_valid_num_graphemes(min, max),
+            None => true,
+        }
+    }
 }
 
 pub trait HasGraphemes {
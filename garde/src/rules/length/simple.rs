@@ -9,14 +9,26 @@ pub fn apply<T: Simple>(v: &T, (min, max): (usize, usize)) -> Result<(), Error>
     v.validate_length(min, max)
 }
 
+pub fn is_valid<T: Simple>(v: &T, (min, max): (usize, usize)) -> bool {
+    v.is_valid_length(min, max)
+}
+
 pub trait Simple {
     fn validate_length(&self, min: usize, max: usize) -> Result<(), Error>;
+
+    fn is_valid_length(&self, min: usize, max: usize) -> bool {
+        self.validate_length(min, max).is_ok()
+    }
 }
 
 impl<T: HasSimpleLength> Simple for T {
     fn validate_length(&self, min: usize, max: usize) -> Result<(), Error> {
         super::check_len(self.length(), min, max)
     }
+
+    fn is_valid_length(&self, min: usize, max: usize) -> bool {
+        super::is_valid_len(self.length(), min, max)
+    }
 }
 
 impl<T: Simple> Simple for Option<T> {
@@ -26,6 +38,13 @@ impl<T: Simple> Simple for Option<T> {
             None => Ok(()),
         }
     }
+
+    fn is_valid_length(&self, min: usize, max: usize) -> bool {
+        match self {
+            Some(v) => v.is_valid_length(min, max),
+            None => true,
+        }
+    }
 }
 
 pub trait HasSimpleLength {
@@ -91,12 +110,20 @@ impl<const N: usize, T> Simple for [T; N] {
     fn validate_length(&self, min: usize, max: usize) -> Result<(), Error> {
         super::check_len(self.len(), min, max)
     }
+
+    fn is_valid_length(&self, min: usize, max: usize) -> bool {
+        super::is_valid_len(self.len(), min, max)
+    }
 }
 
 impl<const N: usize, T> Simple for &[T; N] {
     fn validate_length(&self, min: usize, max: usize) -> Result<(), Error> {
         super::check_len(self.len(), min, max)
     }
+
+    fn is_valid_length(&self, min: usize, max: usize) -> bool {
+        super::is_valid_len(self.len(), min, max)
+    }
 }
 
 impl_via_len!(in<K, V, S> std::collections::HashMap<K, V, S>);
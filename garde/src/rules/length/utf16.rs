@@ -6,14 +6,26 @@ pub fn apply<T: Utf16CodeUnits>(v: &T, (min, max): (usize, usize)) -> Result<(),
     v.validate_num_code_units(min, max)
 }
 
+pub fn is_valid<T: Utf16CodeUnits>(v: &T, (min, max): (usize, usize)) -> bool {
+    v.is_valid_num_code_units(min, max)
+}
+
 pub trait Utf16CodeUnits {
     fn validate_num_code_units(&self, min: usize, max: usize) -> Result<(), Error>;
+
+    fn is_valid_num_code_units(&self, min: usize, max: usize) -> bool {
+        self.validate_num_code_units(min, max).is_ok()
+    }
 }
 
 impl<T: HasUtf16CodeUnits> Utf16CodeUnits for T {
     fn validate_num_code_units(&self, min: usize, max: usize) -> Result<(), Error> {
         super::check_len(self.num_code_units(), min, max)
     }
+
+    fn is_valid_num_code_units(&self, min: usize, max: usize) -> bool {
+        super::is_valid_len(self.num_code_units(), min, max)
+    }
 }
 
 impl<T: Utf16CodeUnits> Utf16CodeUnits for Option<T> {
@@ -23,6 +35,13 @@ impl<T: Utf16CodeUnits> Utf16CodeUnits for Option<T> {
             None => Ok(()),
         }
     }
+
+    fn is_valid_num_code_units(&self, min: usize, max: usize) -> bool {
+        match self {
+            Some(v) => v.is_valid_num_code_units(min, max),
+            None => true,
+        }
+    }
 }
 
 pub trait HasUtf16CodeUnits {
@@ -14,15 +14,29 @@
 //!
 //! This trait has a blanket implementation for all `T: PartialEq<O>, O`.
 
+use crate::i18n::field_label;
 use crate::Error;
 
 pub fn apply<T: Matches<O>, O>(v: &T, (field, value): (&str, &O)) -> Result<(), Error> {
+    apply_labeled(v, (field, None, value))
+}
+
+/// Like [`apply`], but refers to the other field by its `label` in the message.
+pub fn apply_labeled<T: Matches<O>, O>(
+    v: &T,
+    (field, label, value): (&str, Option<&str>, &O),
+) -> Result<(), Error> {
     if !v.validate_matches(value) {
-        return Err(Error::new(i18n!(matches_field_mismatch, &field)));
+        let error = i18n_error!(matches_field_mismatch, &field_label(field, label));
+        return Err(error.with_field(field, label));
     }
     Ok(())
 }
 
+pub fn is_valid<T: Matches<O>, O>(v: &T, (_, value): (&str, &O)) -> bool {
+    v.validate_matches(value)
+}
+
 pub trait Matches<O> {
     fn validate_matches(&self, other: &O) -> bool;
 }
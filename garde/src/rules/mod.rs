@@ -2,6 +2,7 @@
 
 pub mod alphanumeric;
 pub mod ascii;
+pub mod compare;
 pub mod contains;
 #[cfg(feature = "credit-card")]
 pub mod credit_card;
@@ -11,12 +12,16 @@ pub mod inner;
 pub mod ip;
 pub mod length;
 pub mod matches;
+pub mod not_equal;
 pub mod pattern;
 #[cfg(feature = "phone-number")]
 pub mod phone_number;
 pub mod prefix;
 pub mod range;
 pub mod required;
+pub mod required_if;
+pub mod required_with;
+pub mod required_without;
 pub mod suffix;
 #[cfg(feature = "url")]
 pub mod url;
@@ -0,0 +1,104 @@
+//! Field inequality validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(skip)]
+//!     old_password: String,
+//!     #[garde(not_equal(old_password))]
+//!     new_password: String,
+//! }
+//! ```
+//!
+//! This is the inverse of the `matches` rule.
+//!
+//! The entrypoint is the [`NotEqual`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(not_equal)]` rule.
+//!
+//! This trait is implemented for primitive numbers, `char`, `bool`, strings, and the `std::time` types.
+//! For any other `PartialEq` type, an implementation is a single comparison:
+//!
+//! ```rust
+//! use garde::rules::not_equal::NotEqual;
+//!
+//! #[derive(PartialEq)]
+//! struct Version(u32, u32);
+//!
+//! impl NotEqual for Version {
+//!     fn validate_not_equal(&self, other: &Self) -> bool {
+//!         self != other
+//!     }
+//! }
+//! ```
+//!
+//! An `Option` is only compared when both it and the other field are `Some`, like every other rule skips `None`.
+
+use std::borrow::Cow;
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::i18n::field_label;
+use crate::Error;
+
+pub fn apply<T: NotEqual<O>, O>(v: &T, (field, value): (&str, &O)) -> Result<(), Error> {
+    apply_labeled(v, (field, None, value))
+}
+
+/// Like [`apply`], but refers to the other field by its `label` in the message.
+pub fn apply_labeled<T: NotEqual<O>, O>(
+    v: &T,
+    (field, label, value): (&str, Option<&str>, &O),
+) -> Result<(), Error> {
+    if !v.validate_not_equal(value) {
+        let error = i18n_error!(not_equal_field_match, &field_label(field, label));
+        return Err(error.with_field(field, label));
+    }
+    Ok(())
+}
+
+pub fn is_valid<T: NotEqual<O>, O>(v: &T, (_, value): (&str, &O)) -> bool {
+    v.validate_not_equal(value)
+}
+
+pub trait NotEqual<O: ?Sized = Self> {
+    fn validate_not_equal(&self, other: &O) -> bool;
+}
+
+macro_rules! impl_via_partial_eq {
+    ($($T:ty),*) => {
+        $(
+            impl NotEqual for $T {
+                fn validate_not_equal(&self, other: &Self) -> bool {
+                    self != other
+                }
+            }
+        )*
+    };
+}
+
+impl_via_partial_eq!(
+    u8, u16, u32, u64, usize, u128, i8, i16, i32, i64, isize, i128, f32, f64, char, bool, str,
+    String, Duration, Instant, SystemTime
+);
+
+#[cfg(feature = "rust_decimal")]
+impl_via_partial_eq!(rust_decimal::Decimal);
+
+impl NotEqual for Cow<'_, str> {
+    fn validate_not_equal(&self, other: &Self) -> bool {
+        self != other
+    }
+}
+
+impl<T: NotEqual<O> + ?Sized, O: ?Sized> NotEqual<&O> for &T {
+    fn validate_not_equal(&self, other: &&O) -> bool {
+        T::validate_not_equal(self, other)
+    }
+}
+
+impl<T: NotEqual<O>, O> NotEqual<Option<O>> for Option<T> {
+    fn validate_not_equal(&self, other: &Option<O>) -> bool {
+        match (self, other) {
+            (Some(value), Some(other)) => value.validate_not_equal(other),
+            _ => true,
+        }
+    }
+}
@@ -50,11 +50,17 @@ use crate::error::Error;
 
 pub fn apply<T: Pattern, M: Matcher>(v: &T, (pat,): (&M,)) -> Result<(), Error> {
     if !v.validate_pattern(pat) {
-        return Err(Error::new(i18n!(pattern_no_match, &pat.as_str())));
+        return Err(
+            i18n_error!(pattern_no_match, &pat.as_str()).with_param("pattern", pat.as_str())
+        );
     }
     Ok(())
 }
 
+pub fn is_valid<T: Pattern, M: Matcher>(v: &T, (pat,): (&M,)) -> bool {
+    v.validate_pattern(pat)
+}
+
 pub trait Matcher: AsStr {
     /// Returns true if and only if there is a match for the pattern anywhere in the haystack given.
     fn is_match(&self, haystack: &str) -> bool;
@@ -20,11 +20,15 @@ pub use crate::i18n::InvalidPhoneNumber;
 
 pub fn apply<T: PhoneNumber>(v: &T, _: ()) -> Result<(), Error> {
     if let Err(reason) = v.validate_phone_number() {
-        return Err(Error::new(i18n!(phone_number_invalid, reason)));
+        return Err(i18n_error!(phone_number_invalid, reason).with_param("reason", reason.as_str()));
     }
     Ok(())
 }
 
+pub fn is_valid<T: PhoneNumber>(v: &T, _: ()) -> bool {
+    v.validate_phone_number().is_ok()
+}
+
 pub trait PhoneNumber {
     fn validate_phone_number(&self) -> Result<(), InvalidPhoneNumber>;
 }
@@ -21,11 +21,15 @@ use crate::error::Error;
 
 pub fn apply<T: Prefix>(v: &T, (pat,): (&str,)) -> Result<(), Error> {
     if !v.validate_prefix(pat) {
-        return Err(Error::new(i18n!(prefix_missing, &pat)));
+        return Err(i18n_error!(prefix_missing, &pat).with_param("pattern", pat));
     }
     Ok(())
 }
 
+pub fn is_valid<T: Prefix>(v: &T, (pat,): (&str,)) -> bool {
+    v.validate_prefix(pat)
+}
+
 pub trait Prefix {
     fn validate_prefix(&self, pat: &str) -> bool;
 }
@@ -14,7 +14,9 @@
 
 use std::fmt::Display;
 
-use crate::error::Error;
+use compact_str::ToCompactString as _;
+
+use crate::error::{Error, Value};
 
 #[inline]
 pub fn apply<T: Bounds>(
@@ -25,13 +27,22 @@ pub fn apply<T: Bounds>(
     let max = max.unwrap_or(T::MAX);
     if let Err(e) = v.validate_bounds(min, max) {
         match e {
-            OutOfBounds::Lower => return Err(Error::new(i18n!(range_lower_than, &min))),
-            OutOfBounds::Upper => return Err(Error::new(i18n!(range_greater_than, &max))),
+            OutOfBounds::Lower => {
+                return Err(i18n_error!(range_lower_than, &min).with_param("min", T::param(min)))
+            }
+            OutOfBounds::Upper => {
+                return Err(i18n_error!(range_greater_than, &max).with_param("max", T::param(max)))
+            }
         }
     }
     Ok(())
 }
 
+pub fn is_valid<T: Bounds>(v: &T, (min, max): (Option<T::Size>, Option<T::Size>)) -> bool {
+    v.validate_bounds(min.unwrap_or(T::MIN), max.unwrap_or(T::MAX))
+        .is_ok()
+}
+
 pub trait Bounds: PartialOrd {
     type Size: Copy + Sized + Display;
 
@@ -43,6 +54,13 @@ pub trait Bounds: PartialOrd {
         lower_bound: Self::Size,
         upper_bound: Self::Size,
     ) -> Result<(), OutOfBounds>;
+
+    /// Converts a bound into an [`Error`] parameter.
+    ///
+    /// The default implementation uses the bound's `Display` output.
+    fn param(bound: Self::Size) -> Value {
+        Value::Str(bound.to_compact_string())
+    }
 }
 
 pub enum OutOfBounds {
@@ -51,28 +69,40 @@ pub enum OutOfBounds {
 }
 
 macro_rules! impl_for {
-    ($($T:ty),*) => {
-        $(
-            impl Bounds for $T {
-                type Size = $T;
-
-                const MIN: Self::Size = <$T>::MIN;
-                const MAX: Self::Size = <$T>::MAX;
-
-                fn validate_bounds(
-                    &self,
-                    lower_bound: Self::Size,
-                    upper_bound: Self::Size,
-                ) -> Result<(), OutOfBounds> {
-                    if self < &lower_bound {
-                        Err(OutOfBounds::Lower)
-                    } else if self > &upper_bound {
-                        Err(OutOfBounds::Upper)
-                    } else {
-                        Ok(())
-                    }
+    (@impl $T:ty $(, $param:item)?) => {
+        impl Bounds for $T {
+            type Size = $T;
+
+            const MIN: Self::Size = <$T>::MIN;
+            const MAX: Self::Size = <$T>::MAX;
+
+            fn validate_bounds(
+                &self,
+                lower_bound: Self::Size,
+                upper_bound: Self::Size,
+            ) -> Result<(), OutOfBounds> {
+                if self < &lower_bound {
+                    Err(OutOfBounds::Lower)
+                } else if self > &upper_bound {
+                    Err(OutOfBounds::Upper)
+                } else {
+                    Ok(())
                 }
             }
+
+            $($param)?
+        }
+    };
+    (@display $($T:ty),*) => {
+        $(
+            impl_for!(@impl $T);
+        )*
+    };
+    ($($T:ty),*) => {
+        $(
+            impl_for!(@impl $T, fn param(bound: Self::Size) -> Value {
+                Value::from(bound)
+            });
         )*
     };
 }
@@ -80,7 +110,7 @@ macro_rules! impl_for {
 impl_for!(u8, u16, u32, u64, usize, u128, i8, i16, i32, i64, isize, i128, f32, f64);
 
 #[cfg(feature = "rust_decimal")]
-impl_for!(rust_decimal::Decimal);
+impl_for!(@display rust_decimal::Decimal);
 
 impl<T: Bounds> Bounds for Option<T> {
     type Size = T::Size;
@@ -98,4 +128,8 @@ impl<T: Bounds> Bounds for Option<T> {
             None => Ok(()),
         }
     }
+
+    fn param(bound: Self::Size) -> Value {
+        T::param(bound)
+    }
 }
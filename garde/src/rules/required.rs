@@ -1,12 +1,17 @@
-use crate::{Error, Result};
+use crate::validate::is_partial;
+use crate::Result;
 
 pub fn apply<T: Required>(v: &T, _: ()) -> Result {
-    if !v.is_set() {
-        return Err(Error::new(i18n!(required_not_set)));
+    if !v.is_set() && !is_partial() {
+        return Err(i18n_error!(required_not_set));
     }
     Ok(())
 }
 
+pub fn is_valid<T: Required>(v: &T, _: ()) -> bool {
+    v.is_set() || is_partial()
+}
+
 pub trait Required {
     fn is_set(&self) -> bool;
 }
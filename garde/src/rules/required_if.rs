@@ -0,0 +1,35 @@
+//! Conditional presence validation.
+//!
+//! ```rust
+//! #[derive(PartialEq)]
+//! enum Kind {
+//!     Person,
+//!     Company,
+//! }
+//!
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(skip)]
+//!     kind: Kind,
+//!     #[garde(required_if(self.kind == Kind::Company))]
+//!     vat_id: Option<String>,
+//! }
+//! ```
+//!
+//! This rule uses the same [`Required`] trait as the `required` rule. Its errors have the `required_if_not_set` code,
+//! and by default the same message as the `required` rule.
+
+use super::required::Required;
+use crate::validate::is_partial;
+use crate::Result;
+
+pub fn apply<T: Required>(v: &T, (condition,): (bool,)) -> Result {
+    if condition && !v.is_set() && !is_partial() {
+        return Err(i18n_error!(required_if_not_set));
+    }
+    Ok(())
+}
+
+pub fn is_valid<T: Required>(v: &T, (condition,): (bool,)) -> bool {
+    !condition || v.is_set() || is_partial()
+}
@@ -0,0 +1,38 @@
+//! Presence validation depending on another field being set.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(skip)]
+//!     vat_id: Option<String>,
+//!     #[garde(required_with(vat_id))]
+//!     company_name: Option<String>,
+//! }
+//! ```
+//!
+//! This rule uses the same [`Required`] trait as the `required` rule, for both fields.
+
+use super::required::Required;
+use crate::i18n::field_label;
+use crate::validate::is_partial;
+use crate::Result;
+
+pub fn apply<T: Required, O: Required>(v: &T, (field, value): (&str, &O)) -> Result {
+    apply_labeled(v, (field, None, value))
+}
+
+/// Like [`apply`], but refers to the other field by its `label` in the message.
+pub fn apply_labeled<T: Required, O: Required>(
+    v: &T,
+    (field, label, value): (&str, Option<&str>, &O),
+) -> Result {
+    if value.is_set() && !v.is_set() && !is_partial() {
+        let error = i18n_error!(required_with_not_set, &field_label(field, label));
+        return Err(error.with_field(field, label));
+    }
+    Ok(())
+}
+
+pub fn is_valid<T: Required, O: Required>(v: &T, (_, value): (&str, &O)) -> bool {
+    !value.is_set() || v.is_set() || is_partial()
+}
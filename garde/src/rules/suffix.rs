@@ -21,11 +21,15 @@ use crate::error::Error;
 
 pub fn apply<T: Suffix>(v: &T, (pat,): (&str,)) -> Result<(), Error> {
     if !v.validate_suffix(pat) {
-        return Err(Error::new(i18n!(suffix_missing, &pat)));
+        return Err(i18n_error!(suffix_missing, &pat).with_param("pattern", pat));
     }
     Ok(())
 }
 
+pub fn is_valid<T: Suffix>(v: &T, (pat,): (&str,)) -> bool {
+    v.validate_suffix(pat)
+}
+
 pub trait Suffix {
     fn validate_suffix(&self, pat: &str) -> bool;
 }
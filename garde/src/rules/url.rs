@@ -21,11 +21,15 @@ pub use crate::i18n::InvalidUrl;
 
 pub fn apply<T: Url>(v: &T, _: ()) -> Result<(), Error> {
     if let Err(e) = v.validate_url() {
-        return Err(Error::new(i18n!(url_invalid, e)));
+        return Err(i18n_error!(url_invalid, e).with_param("reason", e.as_str()));
     }
     Ok(())
 }
 
+pub fn is_valid<T: Url>(v: &T, _: ()) -> bool {
+    v.validate_url().is_ok()
+}
+
 pub trait Url {
     fn validate_url(&self) -> Result<(), InvalidUrl>;
 }
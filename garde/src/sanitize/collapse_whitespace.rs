@@ -0,0 +1,48 @@
+//! Replaces every run of whitespace with a single space.
+//!
+//! ```rust
+//! #[derive(garde::Sanitize)]
+//! struct Test {
+//!     #[garde(trim, collapse_whitespace)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! This does not remove leading or trailing whitespace, combine it with `trim` for that.
+//!
+//! The entrypoint is the [`CollapseWhitespace`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(collapse_whitespace)]` sanitizer.
+
+pub fn apply<T: CollapseWhitespace>(v: &mut T) {
+    v.sanitize_collapse_whitespace()
+}
+
+pub trait CollapseWhitespace {
+    fn sanitize_collapse_whitespace(&mut self);
+}
+
+impl CollapseWhitespace for String {
+    fn sanitize_collapse_whitespace(&mut self) {
+        let mut out = String::with_capacity(self.len());
+        let mut in_whitespace = false;
+        for c in self.chars() {
+            if c.is_whitespace() {
+                if !in_whitespace {
+                    out.push(' ');
+                }
+                in_whitespace = true;
+            } else {
+                out.push(c);
+                in_whitespace = false;
+            }
+        }
+        *self = out;
+    }
+}
+
+impl<T: CollapseWhitespace> CollapseWhitespace for Option<T> {
+    fn sanitize_collapse_whitespace(&mut self) {
+        if let Some(v) = self {
+            v.sanitize_collapse_whitespace()
+        }
+    }
+}
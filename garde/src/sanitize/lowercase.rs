@@ -0,0 +1,37 @@
+//! Converts a string to lowercase.
+//!
+//! ```rust
+//! #[derive(garde::Sanitize)]
+//! struct Test {
+//!     #[garde(lowercase)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`Lowercase`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(lowercase)]` sanitizer.
+
+pub fn apply<T: Lowercase>(v: &mut T) {
+    v.sanitize_lowercase()
+}
+
+pub trait Lowercase {
+    fn sanitize_lowercase(&mut self);
+}
+
+impl Lowercase for String {
+    fn sanitize_lowercase(&mut self) {
+        if self.is_ascii() {
+            self.make_ascii_lowercase();
+        } else {
+            *self = self.to_lowercase();
+        }
+    }
+}
+
+impl<T: Lowercase> Lowercase for Option<T> {
+    fn sanitize_lowercase(&mut self) {
+        if let Some(v) = self {
+            v.sanitize_lowercase()
+        }
+    }
+}
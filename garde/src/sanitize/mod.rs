@@ -0,0 +1,84 @@
+//! ## Sanitization
+//!
+//! [`Sanitize`] normalizes a value in place, and is meant to run before validation.
+//!
+//! ```rust
+//! use garde::{Sanitize, Unvalidated};
+//!
+//! #[derive(garde::Validate, garde::Sanitize)]
+//! struct User {
+//!     #[garde(trim, lowercase, email)]
+//!     email: String,
+//! }
+//!
+//! let user = User { email: "  Test@Example.com ".into() };
+//! let user = Unvalidated::new(user).sanitize_and_validate().unwrap();
+//! assert_eq!(user.email, "test@example.com");
+//! ```
+//!
+//! Sanitizers run in the order they are written, before any nested value is sanitized through `dive`.
+//! Each sanitizer comes with its own trait, so they may be implemented for custom types.
+
+pub mod collapse_whitespace;
+pub mod lowercase;
+pub mod trim;
+pub mod uppercase;
+
+/// Normalizes `Self` in place.
+pub trait Sanitize {
+    fn sanitize(&mut self);
+}
+
+impl<T: ?Sized + Sanitize> Sanitize for &mut T {
+    fn sanitize(&mut self) {
+        <T as Sanitize>::sanitize(self)
+    }
+}
+
+impl<T: ?Sized + Sanitize> Sanitize for std::boxed::Box<T> {
+    fn sanitize(&mut self) {
+        <T as Sanitize>::sanitize(self)
+    }
+}
+
+macro_rules! impl_sanitize_list {
+    (<$T:ident $(, const $N:ident: usize)?> $Container:ty) => {
+        impl<$T: Sanitize $(, const $N: usize)?> Sanitize for $Container {
+            fn sanitize(&mut self) {
+                for item in self.iter_mut() {
+                    item.sanitize();
+                }
+            }
+        }
+    };
+}
+
+impl_sanitize_list!(<T> std::collections::LinkedList<T>);
+impl_sanitize_list!(<T> std::collections::VecDeque<T>);
+impl_sanitize_list!(<T> std::vec::Vec<T>);
+impl_sanitize_list!(<T> [T]);
+impl_sanitize_list!(<T, const N: usize> [T; N]);
+
+impl<K, V: Sanitize, S> Sanitize for std::collections::HashMap<K, V, S> {
+    fn sanitize(&mut self) {
+        for value in self.values_mut() {
+            value.sanitize();
+        }
+    }
+}
+
+impl<K, V: Sanitize> Sanitize for std::collections::BTreeMap<K, V> {
+    fn sanitize(&mut self) {
+        for value in self.values_mut() {
+            value.sanitize();
+        }
+    }
+}
+
+impl<T: Sanitize> Sanitize for Option<T> {
+    fn sanitize(&mut self) {
+        if let Some(value) = self {
+            value.sanitize()
+        }
+    }
+}
@@ -0,0 +1,36 @@
+//! Removes leading and trailing whitespace.
+//!
+//! ```rust
+//! #[derive(garde::Sanitize)]
+//! struct Test {
+//!     #[garde(trim)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`Trim`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(trim)]` sanitizer.
+
+pub fn apply<T: Trim>(v: &mut T) {
+    v.sanitize_trim()
+}
+
+pub trait Trim {
+    fn sanitize_trim(&mut self);
+}
+
+impl Trim for String {
+    fn sanitize_trim(&mut self) {
+        let end = self.trim_end().len();
+        self.truncate(end);
+        let start = self.len() - self.trim_start().len();
+        self.drain(..start);
+    }
+}
+
+impl<T: Trim> Trim for Option<T> {
+    fn sanitize_trim(&mut self) {
+        if let Some(v) = self {
+            v.sanitize_trim()
+        }
+    }
+}
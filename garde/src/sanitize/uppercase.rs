@@ -0,0 +1,37 @@
+//! Converts a string to uppercase.
+//!
+//! ```rust
+//! #[derive(garde::Sanitize)]
+//! struct Test {
+//!     #[garde(uppercase)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`Uppercase`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(uppercase)]` sanitizer.
+
+pub fn apply<T: Uppercase>(v: &mut T) {
+    v.sanitize_uppercase()
+}
+
+pub trait Uppercase {
+    fn sanitize_uppercase(&mut self);
+}
+
+impl Uppercase for String {
+    fn sanitize_uppercase(&mut self) {
+        if self.is_ascii() {
+            self.make_ascii_uppercase();
+        } else {
+            *self = self.to_uppercase();
+        }
+    }
+}
+
+impl<T: Uppercase> Uppercase for Option<T> {
+    fn sanitize_uppercase(&mut self) {
+        if let Some(v) = self {
+            v.sanitize_uppercase()
+        }
+    }
+}
@@ -0,0 +1,327 @@
+//! ## JSON Schema
+//!
+//! With the `json-schema` feature enabled, `#[derive(Validate)]` also implements [`GardeSchema`],
+//! which describes the type and its constraints as a [JSON Schema](https://json-schema.org/):
+//!
+//! ```rust
+//! use garde::schema::GardeSchema;
+//!
+//! #[derive(garde::Validate)]
+//! struct User {
+//!     #[garde(length(chars, min = 3, max = 25))]
+//!     username: String,
+//!     #[garde(range(min = 18))]
+//!     age: Option<u8>,
+//! }
+//!
+//! let schema = User::json_schema();
+//! assert_eq!(schema["properties"]["username"]["minLength"], 3);
+//! assert_eq!(schema["required"], serde_json::json!(["username"]));
+//! ```
+//!
+//! Only rules with a JSON Schema equivalent are emitted:
+//! - `length` becomes `minItems`/`maxItems` or `minProperties`/`maxProperties`
+//! - `length(chars, ...)` becomes `minLength`/`maxLength`, which count characters. Other `length` modes on strings,
+//!   including the default, which counts bytes, have no equivalent
+//! - `range` becomes `minimum`/`maximum`
+//! - `pattern` with a string literal becomes `pattern`
+//! - `email`, `url`, `ipv4` and `ipv6` become `format`
+//! - `inner` applies to `items`
+//! - `required` adds an `Option` field to `required`, and disallows `null`
+//!
+//! `Option` fields are left out of `required` by default, and also accept `null`, like `serde` does.
+//!
+//! Enums are described as a `oneOf`, using the same externally tagged representation as `serde`.
+//!
+//! Bounds which are not literals, such as `length(min = ctx.min)`, and rules applied through `if` or `groups`
+//! are left out, because they cannot be known ahead of time. Fields whose type does not implement [`GardeSchema`]
+//! are described by an empty schema.
+
+pub use serde_json::{Map, Value};
+
+/// Describes `Self` as a JSON Schema.
+pub trait GardeSchema {
+    /// Whether a field of this type may be left out. This is only `true` for `Option<T>`.
+    const OPTIONAL: bool = false;
+
+    /// Returns the JSON Schema for `Self`.
+    fn json_schema() -> Value;
+}
+
+fn schema<const N: usize>(entries: [(&str, Value); N]) -> Value {
+    Value::Object(
+        entries
+            .into_iter()
+            .map(|(key, value)| (key.to_owned(), value))
+            .collect(),
+    )
+}
+
+macro_rules! impl_schema_type {
+    ($ty:literal => $($T:ty),*) => {
+        $(
+            impl GardeSchema for $T {
+                fn json_schema() -> Value {
+                    schema([("type", $ty.into())])
+                }
+            }
+        )*
+    };
+}
+
+impl_schema_type!("string" => str, String, char, std::borrow::Cow<'_, str>);
+impl_schema_type!("boolean" => bool);
+impl_schema_type!("integer" => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_schema_type!("number" => f32, f64);
+impl_schema_type!("null" => ());
+
+macro_rules! impl_schema_deref {
+    (<$T:ident> $Container:ty) => {
+        impl<$T: ?Sized + GardeSchema> GardeSchema for $Container {
+            const OPTIONAL: bool = $T::OPTIONAL;
+
+            fn json_schema() -> Value {
+                $T::json_schema()
+            }
+        }
+    };
+}
+
+impl_schema_deref!(<T> &T);
+impl_schema_deref!(<T> &mut T);
+impl_schema_deref!(<T> std::boxed::Box<T>);
+impl_schema_deref!(<T> std::rc::Rc<T>);
+impl_schema_deref!(<T> std::sync::Arc<T>);
+
+impl<T: GardeSchema> GardeSchema for Option<T> {
+    const OPTIONAL: bool = true;
+
+    fn json_schema() -> Value {
+        nullable(T::json_schema())
+    }
+}
+
+macro_rules! impl_schema_list {
+    (<$T:ident $(, $Other:ident)*> $Container:ty $(, $unique:literal)?) => {
+        impl<$T: GardeSchema, $($Other),*> GardeSchema for $Container {
+            fn json_schema() -> Value {
+                schema([
+                    ("type", "array".into()),
+                    ("items", $T::json_schema()),
+                    $(("uniqueItems", $unique.into()),)?
+                ])
+            }
+        }
+    };
+}
+
+impl_schema_list!(<T, S> std::collections::HashSet<T, S>, true);
+impl_schema_list!(<T> std::collections::BTreeSet<T>, true);
+impl_schema_list!(<T> std::collections::BinaryHeap<T>);
+impl_schema_list!(<T> std::collections::LinkedList<T>);
+impl_schema_list!(<T> std::collections::VecDeque<T>);
+impl_schema_list!(<T> std::vec::Vec<T>);
+impl_schema_list!(<T> [T]);
+
+impl<T: GardeSchema, const N: usize> GardeSchema for [T; N] {
+    fn json_schema() -> Value {
+        schema([
+            ("type", "array".into()),
+            ("items", T::json_schema()),
+            ("minItems", N.into()),
+            ("maxItems", N.into()),
+        ])
+    }
+}
+
+impl<K, V: GardeSchema, S> GardeSchema for std::collections::HashMap<K, V, S> {
+    fn json_schema() -> Value {
+        schema([
+            ("type", "object".into()),
+            ("additionalProperties", V::json_schema()),
+        ])
+    }
+}
+
+impl<K, V: GardeSchema> GardeSchema for std::collections::BTreeMap<K, V> {
+    fn json_schema() -> Value {
+        schema([
+            ("type", "object".into()),
+            ("additionalProperties", V::json_schema()),
+        ])
+    }
+}
+
+/// Builds an object schema from its `properties` and the names of its `required` properties.
+pub fn object(properties: Map<String, Value>, required: Vec<&str>) -> Value {
+    schema([
+        ("type", "object".into()),
+        ("properties", Value::Object(properties)),
+        ("required", required.into()),
+    ])
+}
+
+/// Builds a schema for a tuple, where each item is described by the schema at the same index.
+pub fn tuple(items: Vec<Value>) -> Value {
+    let len = items.len();
+    schema([
+        ("type", "array".into()),
+        ("prefixItems", items.into()),
+        ("minItems", len.into()),
+        ("maxItems", len.into()),
+    ])
+}
+
+/// Builds a schema which matches exactly one of `variants`.
+pub fn one_of(variants: Vec<Value>) -> Value {
+    schema([("oneOf", variants.into())])
+}
+
+/// Builds a schema for a unit enum variant, which is represented by its name.
+pub fn constant(name: &str) -> Value {
+    schema([("const", name.into())])
+}
+
+/// Builds a schema for an enum variant with fields, which is represented as `{ name: value }`.
+pub fn variant(name: &str, value: Value) -> Value {
+    let mut properties = Map::new();
+    properties.insert(name.to_owned(), value);
+    object(properties, vec![name])
+}
+
+/// Returns the `type` of `schema`, ignoring `null` if it is [nullable][`nullable`].
+pub fn type_of(schema: &Value) -> Option<&str> {
+    match &schema["type"] {
+        Value::String(ty) => Some(ty),
+        Value::Array(types) => types
+            .iter()
+            .filter_map(Value::as_str)
+            .find(|ty| *ty != "null"),
+        _ => None,
+    }
+}
+
+/// Allows `null` in addition to the values described by `schema`.
+pub fn nullable(mut schema: Value) -> Value {
+    match schema.get("type") {
+        Some(Value::String(ty)) if ty != "null" => {
+            schema["type"] = Value::from(vec![ty.clone(), "null".to_owned()]);
+            schema
+        }
+        Some(_) => schema,
+        // An empty schema already allows `null`.
+        None if schema.as_object().is_some_and(Map::is_empty) => schema,
+        None => self::schema([(
+            "anyOf",
+            Value::from(vec![schema, self::schema([("type", "null".into())])]),
+        )]),
+    }
+}
+
+/// Disallows `null` in a schema made [nullable][`nullable`].
+pub fn non_null(schema: &mut Value) {
+    if schema["type"].is_array() {
+        if let Some(ty) = type_of(schema).map(str::to_owned) {
+            schema["type"] = ty.into();
+        }
+    } else if let Some([inner, null]) = schema["anyOf"].as_array().map(Vec::as_slice) {
+        if null["type"] == "null" {
+            let inner = inner.clone();
+            *schema = inner;
+        }
+    }
+}
+
+/// Adds `minItems`/`maxItems` or `minProperties`/`maxProperties` to `schema`, depending on its `type`.
+///
+/// Strings are left alone, because the default `length` mode counts bytes, which JSON Schema has no keyword for.
+pub fn length(schema: &mut Value, min: Option<usize>, max: Option<usize>) {
+    match type_of(schema) {
+        Some("array") => bounds(schema, ("minItems", min), ("maxItems", max)),
+        Some("object") => bounds(schema, ("minProperties", min), ("maxProperties", max)),
+        _ => {}
+    }
+}
+
+/// Adds `minLength`/`maxLength` to a string `schema`, which count characters like `length(chars, ...)`.
+pub fn char_length(schema: &mut Value, min: Option<usize>, max: Option<usize>) {
+    if type_of(schema) == Some("string") {
+        bounds(schema, ("minLength", min), ("maxLength", max));
+    }
+}
+
+fn bounds(
+    schema: &mut Value,
+    (min_key, min): (&str, Option<usize>),
+    (max_key, max): (&str, Option<usize>),
+) {
+    if let Some(min) = min.filter(|min| *min > 0) {
+        schema[min_key] = min.into();
+    }
+    if let Some(max) = max.filter(|max| *max < usize::MAX) {
+        schema[max_key] = max.into();
+    }
+}
+
+/// Adds `minimum` and `maximum` to `schema`.
+pub fn range(schema: &mut Value, min: Option<Value>, max: Option<Value>) {
+    if let Some(min) = min {
+        schema["minimum"] = min;
+    }
+    if let Some(max) = max {
+        schema["maximum"] = max;
+    }
+}
+
+/// Sets the `format` of `schema`.
+pub fn format(schema: &mut Value, format: &str) {
+    schema["format"] = format.into();
+}
+
+/// Sets the `pattern` of `schema`.
+pub fn pattern(schema: &mut Value, pattern: &str) {
+    schema["pattern"] = pattern.into();
+}
+
+/// Returns the schema which `inner` rules apply to, which is `items` for arrays and `additionalProperties` for objects.
+pub fn inner(schema: &mut Value) -> Option<&mut Value> {
+    let key = match type_of(schema) {
+        Some("array") => "items",
+        Some("object") => "additionalProperties",
+        _ => return None,
+    };
+    schema.get_mut(key)
+}
+
+#[doc(hidden)]
+pub mod __private {
+    //! Resolves to the [`GardeSchema`] impl of `T` if there is one, and to an empty schema otherwise.
+    //!
+    //! This relies on method resolution preferring `&Wrap<T>` over `Wrap<T>` when called on `&&Wrap<T>`.
+
+    use std::marker::PhantomData;
+
+    use super::*;
+
+    pub struct Wrap<T: ?Sized>(pub PhantomData<T>);
+
+    pub trait ViaSchema {
+        fn schema(&self) -> (Value, bool);
+    }
+
+    impl<T: ?Sized + GardeSchema> ViaSchema for &Wrap<T> {
+        fn schema(&self) -> (Value, bool) {
+            (T::json_schema(), T::OPTIONAL)
+        }
+    }
+
+    pub trait ViaFallback {
+        fn schema(&self) -> (Value, bool);
+    }
+
+    impl<T: ?Sized> ViaFallback for Wrap<T> {
+        fn schema(&self) -> (Value, bool) {
+            (Value::Object(Map::new()), false)
+        }
+    }
+}
@@ -1,8 +1,15 @@
 //! ## Core validation traits and types
 
+use std::cell::Cell;
 use std::fmt::Debug;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
 
 use crate::error::{Path, PathComponentKind};
+use crate::i18n::I18n;
+use crate::introspect::FieldRules;
+use crate::sanitize::Sanitize;
 use crate::Report;
 
 /// The core trait of this crate.
@@ -42,6 +49,120 @@ pub trait Validate {
         }
     }
 
+    /// Validates `Self`, returning an `Err` with an aggregate of all errors if
+    /// the validation failed. Error messages are produced by `i18n` instead of the
+    /// handler installed by [`with_i18n`][`crate::with_i18n`].
+    ///
+    /// Unlike a surrounding [`with_i18n`][`crate::with_i18n`], the handler is passed explicitly,
+    /// so it can be chosen per call, e.g. from a request's `Accept-Language` header.
+    ///
+    /// This method should not be implemented manually. Implement [`Validate::validate_into`] instead,
+    /// because [`Validate::validate_with_i18n`] has a default implementation that calls [`Validate::validate_into`].
+    fn validate_with_i18n(&self, ctx: &Self::Context, i18n: &dyn I18n) -> Result<(), Report> {
+        crate::i18n::with_i18n(i18n, || self.validate_with(ctx))
+    }
+
+    /// Validates `Self`, stopping once `limit` errors have been found,
+    /// and returning an `Err` with an aggregate of those errors if the validation failed.
+    ///
+    /// A `limit` of `1` stops at the first error, which is useful when only a yes/no answer is needed.
+    /// A `limit` of `0` means no limit, the same as [`Validate::validate_with`].
+    ///
+    /// This method should not be implemented manually. Implement [`Validate::validate_into`] instead,
+    /// because [`Validate::validate_with_limit`] has a default implementation that calls [`Validate::validate_into`].
+    fn validate_with_limit(&self, ctx: &Self::Context, limit: usize) -> Result<(), Report> {
+        let mut report = Report::with_limit(limit);
+        self.validate_into(ctx, &mut Path::empty, &mut report);
+        match report.is_empty() {
+            true => Ok(()),
+            false => Err(report),
+        }
+    }
+
+    /// Validates `Self` with `G` as the active validation [group][`crate::group`],
+    /// returning an `Err` with an aggregate of all errors if the validation failed.
+    ///
+    /// This method should not be implemented manually. Implement [`Validate::validate_into`] instead,
+    /// because [`Validate::validate_group`] has a default implementation that calls [`Validate::validate_into`].
+    fn validate_group<G: ?Sized + 'static>(&self) -> Result<(), Report>
+    where
+        Self: Sized,
+        Self::Context: Default,
+    {
+        let ctx = Self::Context::default();
+        self.validate_group_with::<G>(&ctx)
+    }
+
+    /// Validates `Self` with `G` as the active validation [group][`crate::group`],
+    /// returning an `Err` with an aggregate of all errors if the validation failed.
+    ///
+    /// This method should not be implemented manually. Implement [`Validate::validate_into`] instead,
+    /// because [`Validate::validate_group_with`] has a default implementation that calls [`Validate::validate_into`].
+    fn validate_group_with<G: ?Sized + 'static>(&self, ctx: &Self::Context) -> Result<(), Report>
+    where
+        Self: Sized,
+    {
+        crate::group::with_group::<G, _>(|| self.validate_with(ctx))
+    }
+
+    /// Validates `Self` as a partial update, returning an `Err` with an aggregate
+    /// of all errors if the validation failed.
+    ///
+    /// See [`with_partial`] for what this changes.
+    ///
+    /// This method should not be implemented manually. Implement [`Validate::validate_into`] instead,
+    /// because [`Validate::validate_partial`] has a default implementation that calls [`Validate::validate_into`].
+    fn validate_partial(&self) -> Result<(), Report>
+    where
+        Self::Context: Default,
+    {
+        let ctx = Self::Context::default();
+        self.validate_partial_with(&ctx)
+    }
+
+    /// Validates `Self` as a partial update, returning an `Err` with an aggregate
+    /// of all errors if the validation failed.
+    ///
+    /// See [`with_partial`] for what this changes.
+    ///
+    /// This method should not be implemented manually. Implement [`Validate::validate_into`] instead,
+    /// because [`Validate::validate_partial_with`] has a default implementation that calls [`Validate::validate_into`].
+    fn validate_partial_with(&self, ctx: &Self::Context) -> Result<(), Report> {
+        with_partial(|| self.validate_with(ctx))
+    }
+
+    /// Returns `true` if `Self` is valid.
+    ///
+    /// This method should not be implemented manually. Implement [`Validate::is_valid_with`] instead,
+    /// because [`Validate::is_valid`] has a default implementation that calls [`Validate::is_valid_with`].
+    fn is_valid(&self) -> bool
+    where
+        Self::Context: Default,
+    {
+        let ctx = Self::Context::default();
+        self.is_valid_with(&ctx)
+    }
+
+    /// Returns `true` if `Self` is valid.
+    ///
+    /// Unlike [`Validate::validate_with`], this does not build a [`Report`], so derived implementations
+    /// never construct an [`Error`][`crate::Error`] or [`Path`] for the built-in rules, and stop at the first failure.
+    ///
+    /// The default implementation calls [`Validate::validate_with_limit`] with a limit of `1`.
+    fn is_valid_with(&self, ctx: &Self::Context) -> bool {
+        self.validate_with_limit(ctx, 1).is_ok()
+    }
+
+    /// Describes the rules which apply to each field of `Self`.
+    ///
+    /// See [`introspect`][`crate::introspect`] for details. The default implementation returns no fields.
+    fn rules() -> &'static [FieldRules]
+    where
+        Self: Sized,
+    {
+        &[]
+    }
+
     /// Validates `Self`, aggregating all validation errors into `Report`.
     fn validate_into(
         &self,
@@ -51,13 +172,94 @@ pub trait Validate {
     );
 }
 
+thread_local! {
+    static PARTIAL: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Execute a closure with partial validation enabled.
+///
+/// This is intended for partial updates, such as `PATCH` payloads, where every field is an `Option`
+/// and only the fields which are present should be validated. Fields whose type is written as an
+/// `Option` are not validated at all while they are `None`, including by `custom` rules and rules such
+/// as `matches` which refer to other fields. `required`, `required_if`, `required_with` and
+/// `required_without` always pass.
+///
+/// Partial validation is only enabled for the current thread, and also applies to nested values.
+/// Use [`with_partial_async`] to enable it for a future, such as one returned by
+/// [`AsyncValidate::validate_async`][`crate::AsyncValidate::validate_async`].
+pub fn with_partial<R>(f: impl FnOnce() -> R) -> R {
+    // Stack guard which restores the previous value of `PARTIAL` on drop.
+    struct Reset {
+        prev: bool,
+    }
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            PARTIAL.with(|c| c.set(self.prev));
+        }
+    }
+
+    let _reset = Reset {
+        prev: PARTIAL.with(|c| c.replace(true)),
+    };
+    f()
+}
+
+/// Returns `true` if partial validation is currently enabled. See [`with_partial`].
+pub fn is_partial() -> bool {
+    PARTIAL.with(|c| c.get())
+}
+
+/// Returns `true` if `value` should not be validated, because it is absent in partial validation.
+#[doc(hidden)]
+pub fn is_absent<T>(value: &Option<T>) -> bool {
+    value.is_none() && is_partial()
+}
+
+/// Run a future with partial validation enabled. See [`with_partial`].
+///
+/// Unlike wrapping the creation of a future in [`with_partial`], partial validation is enabled
+/// every time the returned future is polled, so it applies across `.await` points,
+/// even if the future moves between threads or interleaves with other tasks.
+///
+/// ```rust,ignore
+/// let result = garde::validate::with_partial_async(patch.validate_async_with(&db)).await;
+/// ```
+pub fn with_partial_async<F: Future>(future: F) -> WithPartial<F> {
+    WithPartial { future }
+}
+
+/// A future which enables partial validation while it is polled.
+///
+/// Created by [`with_partial_async`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WithPartial<F> {
+    future: F,
+}
+
+impl<F: Future> Future for WithPartial<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `future` is never moved out of `self`.
+        let future = unsafe { self.map_unchecked_mut(|this| &mut this.future) };
+        with_partial(|| future.poll(cx))
+    }
+}
+
 /// A struct which wraps a valid instance of some `T`.
 ///
 /// The only way to create an instance of this struct is through the `validate`
 /// function on the [`Unvalidated`] type. This ensures that if you have a `Valid<T>`,
 /// it was definitely validated at some point. This is commonly referred to as the
 /// typestate pattern.
+///
+/// With the `serde` feature, `Valid<T>` serializes transparently, and deserializes by
+/// deserializing a `T` and then validating it with a default context. Validation
+/// errors are surfaced as a custom deserialization error containing the [`Report`].
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct Valid<T>(T);
 
 impl<T: Validate> Valid<T> {
@@ -75,6 +277,22 @@ impl<T> std::ops::Deref for Valid<T> {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Valid<T>
+where
+    T: serde::Deserialize<'de> + Validate,
+    <T as Validate>::Context: Default,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Unvalidated::<T>::deserialize(deserializer)?
+            .validate()
+            .map_err(|report| serde::de::Error::custom(report.to_string().trim_end()))
+    }
+}
+
 /// A struct which wraps a potentially invalid instance of some `T`.
 ///
 /// Use the `validate` method to turn this type into a `Valid<T>`.
@@ -106,6 +324,38 @@ impl<T: Validate> Unvalidated<T> {
         self.0.validate_with(ctx)?;
         Ok(Valid(self.0))
     }
+
+    /// Validates `self` with error messages produced by `i18n`, transforming it into a `Valid<T>`.
+    ///
+    /// See [`Validate::validate_with_i18n`].
+    pub fn validate_with_i18n(
+        self,
+        ctx: &<T as Validate>::Context,
+        i18n: &dyn I18n,
+    ) -> Result<Valid<T>, Report> {
+        self.0.validate_with_i18n(ctx, i18n)?;
+        Ok(Valid(self.0))
+    }
+}
+
+impl<T: Validate + Sanitize> Unvalidated<T> {
+    /// [Sanitizes][`Sanitize`] `self`, and then validates it, transforming it into a `Valid<T>`.
+    pub fn sanitize_and_validate(self) -> Result<Valid<T>, Report>
+    where
+        <T as Validate>::Context: Default,
+    {
+        let ctx = <T as Validate>::Context::default();
+        self.sanitize_and_validate_with(&ctx)
+    }
+
+    /// [Sanitizes][`Sanitize`] `self`, and then validates it, transforming it into a `Valid<T>`.
+    pub fn sanitize_and_validate_with(
+        mut self,
+        ctx: &<T as Validate>::Context,
+    ) -> Result<Valid<T>, Report> {
+        self.0.sanitize();
+        self.validate_with(ctx)
+    }
 }
 
 impl<T: Validate> From<T> for Unvalidated<T> {
@@ -131,6 +381,10 @@ impl<T: ?Sized + Validate> Validate for &T {
     ) {
         <T as Validate>::validate_into(self, ctx, parent, report)
     }
+
+    fn is_valid_with(&self, ctx: &Self::Context) -> bool {
+        <T as Validate>::is_valid_with(self, ctx)
+    }
 }
 
 impl<T: ?Sized + Validate> Validate for &mut T {
@@ -144,6 +398,10 @@ impl<T: ?Sized + Validate> Validate for &mut T {
     ) {
         <T as Validate>::validate_into(self, ctx, parent, report)
     }
+
+    fn is_valid_with(&self, ctx: &Self::Context) -> bool {
+        <T as Validate>::is_valid_with(self, ctx)
+    }
 }
 
 impl<T: Validate> Validate for std::boxed::Box<T> {
@@ -157,6 +415,14 @@ impl<T: Validate> Validate for std::boxed::Box<T> {
     ) {
         <T as Validate>::validate_into(self, ctx, parent, report)
     }
+
+    fn is_valid_with(&self, ctx: &Self::Context) -> bool {
+        <T as Validate>::is_valid_with(self, ctx)
+    }
+
+    fn rules() -> &'static [FieldRules] {
+        <T as Validate>::rules()
+    }
 }
 
 impl<T: Validate> Validate for std::rc::Rc<T> {
@@ -170,6 +436,14 @@ impl<T: Validate> Validate for std::rc::Rc<T> {
     ) {
         <T as Validate>::validate_into(self, ctx, parent, report)
     }
+
+    fn is_valid_with(&self, ctx: &Self::Context) -> bool {
+        <T as Validate>::is_valid_with(self, ctx)
+    }
+
+    fn rules() -> &'static [FieldRules] {
+        <T as Validate>::rules()
+    }
 }
 
 impl<T: Validate> Validate for std::sync::Arc<T> {
@@ -183,10 +457,26 @@ impl<T: Validate> Validate for std::sync::Arc<T> {
     ) {
         <T as Validate>::validate_into(self, ctx, parent, report)
     }
+
+    fn is_valid_with(&self, ctx: &Self::Context) -> bool {
+        <T as Validate>::is_valid_with(self, ctx)
+    }
+
+    fn rules() -> &'static [FieldRules] {
+        <T as Validate>::rules()
+    }
 }
 
 macro_rules! impl_validate_list {
     (<$T:ident $(, $Other:ident)*> $Container:ty) => {
+        impl_validate_list!(<$T $(, $Other)*> $Container, {
+            fn rules() -> &'static [FieldRules] {
+                <T as Validate>::rules()
+            }
+        });
+    };
+    // Unsized containers cannot forward `rules`, which requires `Self: Sized`.
+    (<$T:ident $(, $Other:ident)*> $Container:ty, { $($rules:tt)* }) => {
         impl<$T, $($Other),*> Validate for $Container
         where
             $T: Validate
@@ -195,10 +485,19 @@ macro_rules! impl_validate_list {
 
             fn validate_into(&self, ctx: &Self::Context, mut parent: &mut dyn FnMut() -> Path, report: &mut Report) {
                 for (index, item) in self.iter().enumerate() {
+                    if report.is_full() {
+                        break;
+                    }
                     let mut path = $crate::util::nested_path!(parent, index);
                     <T as Validate>::validate_into(item, ctx, &mut path, report);
                 }
             }
+
+            fn is_valid_with(&self, ctx: &Self::Context) -> bool {
+                self.iter().all(|item| <T as Validate>::is_valid_with(item, ctx))
+            }
+
+            $($rules)*
         }
     };
 }
@@ -209,7 +508,7 @@ impl_validate_list!(<T> std::collections::BinaryHeap<T>);
 impl_validate_list!(<T> std::collections::LinkedList<T>);
 impl_validate_list!(<T> std::collections::VecDeque<T>);
 impl_validate_list!(<T> std::vec::Vec<T>);
-impl_validate_list!(<T> [T]);
+impl_validate_list!(<T> [T], {});
 
 impl<T: Validate, const N: usize> Validate for [T; N] {
     type Context = T::Context;
@@ -221,10 +520,22 @@ impl<T: Validate, const N: usize> Validate for [T; N] {
         report: &mut Report,
     ) {
         for (index, item) in self.iter().enumerate() {
+            if report.is_full() {
+                break;
+            }
             let mut path = crate::util::nested_path!(parent, index);
             <T as Validate>::validate_into(item, ctx, &mut path, report);
         }
     }
+
+    fn is_valid_with(&self, ctx: &Self::Context) -> bool {
+        self.iter()
+            .all(|item| <T as Validate>::is_valid_with(item, ctx))
+    }
+
+    fn rules() -> &'static [FieldRules] {
+        <T as Validate>::rules()
+    }
 }
 
 macro_rules! impl_validate_tuple {
@@ -254,6 +565,12 @@ macro_rules! impl_validate_tuple {
                 })*
                 let _ = index;
             }
+
+            #[allow(non_snake_case)]
+            fn is_valid_with(&self, ctx: &Self::Context) -> bool {
+                let ($A, $($T,)*) = self;
+                <$A as Validate>::is_valid_with($A, ctx) $(&& <$T as Validate>::is_valid_with($T, ctx))*
+            }
         }
     }
 }
@@ -275,6 +592,10 @@ impl Validate for () {
     type Context = ();
 
     fn validate_into(&self, _: &Self::Context, _: &mut dyn FnMut() -> Path, _: &mut Report) {}
+
+    fn is_valid_with(&self, _: &Self::Context) -> bool {
+        true
+    }
 }
 
 impl<K, V, S> Validate for std::collections::HashMap<K, V, S>
@@ -291,10 +612,22 @@ where
         report: &mut Report,
     ) {
         for (key, value) in self.iter() {
+            if report.is_full() {
+                break;
+            }
             let mut path = crate::util::nested_path!(parent, key);
             <V as Validate>::validate_into(value, ctx, &mut path, report);
         }
     }
+
+    fn is_valid_with(&self, ctx: &Self::Context) -> bool {
+        self.values()
+            .all(|value| <V as Validate>::is_valid_with(value, ctx))
+    }
+
+    fn rules() -> &'static [FieldRules] {
+        <V as Validate>::rules()
+    }
 }
 
 impl<K, V> Validate for std::collections::BTreeMap<K, V>
@@ -311,10 +644,22 @@ where
         report: &mut Report,
     ) {
         for (key, value) in self.iter() {
+            if report.is_full() {
+                break;
+            }
             let mut path = crate::util::nested_path!(parent, key);
             <V as Validate>::validate_into(value, ctx, &mut path, report);
         }
     }
+
+    fn is_valid_with(&self, ctx: &Self::Context) -> bool {
+        self.values()
+            .all(|value| <V as Validate>::is_valid_with(value, ctx))
+    }
+
+    fn rules() -> &'static [FieldRules] {
+        <V as Validate>::rules()
+    }
 }
 
 impl<T: Validate> Validate for Option<T> {
@@ -330,6 +675,17 @@ impl<T: Validate> Validate for Option<T> {
             value.validate_into(ctx, parent, report)
         }
     }
+
+    fn is_valid_with(&self, ctx: &Self::Context) -> bool {
+        match self {
+            Some(value) => value.is_valid_with(ctx),
+            None => true,
+        }
+    }
+
+    fn rules() -> &'static [FieldRules] {
+        <T as Validate>::rules()
+    }
 }
 
 impl<B: Validate> Validate for std::borrow::Cow<'_, B>
@@ -346,4 +702,12 @@ where
     ) {
         self.as_ref().validate_into(ctx, parent, report)
     }
+
+    fn is_valid_with(&self, ctx: &Self::Context) -> bool {
+        self.as_ref().is_valid_with(ctx)
+    }
+
+    fn rules() -> &'static [FieldRules] {
+        <B as Validate>::rules()
+    }
 }
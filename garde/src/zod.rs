@@ -0,0 +1,192 @@
+//! ## Zod schema generation
+//!
+//! With the `zod` feature enabled, the [JSON Schema][`crate::schema`] of `#[derive(Validate)]` types
+//! can be turned into [Zod](https://zod.dev/) schemas, so client-side checks stay in sync with server-side validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct User {
+//!     #[garde(length(chars, min = 3, max = 25))]
+//!     username: String,
+//!     #[garde(email)]
+//!     email: Option<String>,
+//! }
+//!
+//! let output = garde::zod::Generator::new().add::<User>("User").generate();
+//! assert!(output.contains(concat!(
+//!     "export const User = z.object({ email: z.string().email().nullish(), ",
+//!     "username: z.string().refine((v) => [...v].length >= 3).refine((v) => [...v].length <= 25) });",
+//! )));
+//! ```
+//!
+//! [`Generator::write_to`] writes the generated module to a file, which is meant to be called
+//! from a build script or a test, and checked into the front end's sources.
+//!
+//! Fields which are not `required` accept both `undefined` and `null`, matching how `serde` deserializes `Option`.
+//! String lengths are checked with `refine`, because `minLength` and `maxLength` count characters,
+//! while Zod's `min` and `max` count UTF-16 code units.
+//! Patterns are passed to JavaScript's `RegExp` as-is, so they should only use syntax both engines understand.
+
+use std::fmt::Write as _;
+
+use crate::schema::{non_null, type_of, GardeSchema, Value};
+
+/// Collects named schemas and generates a TypeScript module which exports them.
+#[derive(Debug, Default, Clone)]
+pub struct Generator {
+    schemas: Vec<(String, Value)>,
+}
+
+impl Generator {
+    /// Creates an empty generator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the schema of `T`, exported as `name`.
+    pub fn add<T: GardeSchema>(&mut self, name: impl Into<String>) -> &mut Self {
+        self.schemas.push((name.into(), T::json_schema()));
+        self
+    }
+
+    /// Generates the TypeScript module, which exports a schema and an inferred type for each added type.
+    pub fn generate(&self) -> String {
+        let mut output = String::new();
+        output.push_str("// This file is generated by garde. Do not edit it by hand.\n\n");
+        output.push_str("import { z } from \"zod\";\n");
+        for (name, schema) in &self.schemas {
+            let _ = write!(
+                output,
+                "\nexport const {name} = {};\nexport type {name} = z.infer<typeof {name}>;\n",
+                to_zod(schema)
+            );
+        }
+        output
+    }
+
+    /// Generates the TypeScript module and writes it to `path`.
+    pub fn write_to(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        std::fs::write(path, self.generate())
+    }
+}
+
+/// Converts a JSON Schema produced by [`GardeSchema`] into a Zod expression.
+///
+/// Keywords which are not produced by [`GardeSchema`] are ignored, and schemas without a `type` become `z.unknown()`.
+pub fn to_zod(schema: &Value) -> String {
+    if let Some(schema) = without_null(schema) {
+        return format!("{}.nullable()", to_zod(&schema));
+    }
+    if let Some(variants) = schema["oneOf"].as_array() {
+        let variants = variants.iter().map(to_zod).collect::<Vec<_>>();
+        return format!("z.union([{}])", variants.join(", "));
+    }
+    if !schema["const"].is_null() {
+        return format!("z.literal({})", schema["const"]);
+    }
+
+    match type_of(schema) {
+        Some("string") => {
+            let mut output = String::from("z.string()");
+            match schema["format"].as_str() {
+                Some("email") => output.push_str(".email()"),
+                Some("uri") => output.push_str(".url()"),
+                Some("ipv4") => output.push_str(".ip({ version: \"v4\" })"),
+                Some("ipv6") => output.push_str(".ip({ version: \"v6\" })"),
+                _ => {}
+            }
+            if let Some(min) = schema.get("minLength") {
+                let _ = write!(output, ".refine((v) => [...v].length >= {min})");
+            }
+            if let Some(max) = schema.get("maxLength") {
+                let _ = write!(output, ".refine((v) => [...v].length <= {max})");
+            }
+            if let Some(pattern) = schema.get("pattern") {
+                let _ = write!(output, ".regex(new RegExp({pattern}))");
+            }
+            output
+        }
+        Some("integer") => {
+            let mut output = String::from("z.number().int()");
+            bounds(&mut output, schema, "minimum", "maximum");
+            output
+        }
+        Some("number") => {
+            let mut output = String::from("z.number()");
+            bounds(&mut output, schema, "minimum", "maximum");
+            output
+        }
+        Some("boolean") => String::from("z.boolean()"),
+        Some("null") => String::from("z.null()"),
+        Some("array") => match schema["prefixItems"].as_array() {
+            Some(items) => {
+                let items = items.iter().map(to_zod).collect::<Vec<_>>();
+                format!("z.tuple([{}])", items.join(", "))
+            }
+            None => {
+                let mut output = format!("z.array({})", to_zod(&schema["items"]));
+                bounds(&mut output, schema, "minItems", "maxItems");
+                output
+            }
+        },
+        Some("object") => match schema["properties"].as_object() {
+            Some(properties) => {
+                let required = schema["required"].as_array();
+                let is_required =
+                    |key: &str| required.is_some_and(|required| required.iter().any(|v| v == key));
+                let properties = properties
+                    .iter()
+                    .map(|(key, value)| {
+                        let value = match is_required(key) {
+                            true => to_zod(value),
+                            false => match without_null(value) {
+                                Some(value) => format!("{}.nullish()", to_zod(&value)),
+                                None => format!("{}.nullish()", to_zod(value)),
+                            },
+                        };
+                        format!("{}: {value}", property_key(key))
+                    })
+                    .collect::<Vec<_>>();
+                match properties.is_empty() {
+                    true => String::from("z.object({})"),
+                    false => format!("z.object({{ {} }})", properties.join(", ")),
+                }
+            }
+            // Zod has no size constraints on records, so `minProperties` and `maxProperties` are left out.
+            None => format!(
+                "z.record(z.string(), {})",
+                to_zod(&schema["additionalProperties"])
+            ),
+        },
+        _ => String::from("z.unknown()"),
+    }
+}
+
+/// Returns `schema` without `null` if it is nullable, so that `null` can be handled by Zod's modifiers.
+fn without_null(schema: &Value) -> Option<Value> {
+    let mut without = schema.clone();
+    non_null(&mut without);
+    (without != *schema).then_some(without)
+}
+
+fn bounds(output: &mut String, schema: &Value, min: &str, max: &str) {
+    if let Some(min) = schema.get(min) {
+        let _ = write!(output, ".min({min})");
+    }
+    if let Some(max) = schema.get(max) {
+        let _ = write!(output, ".max({max})");
+    }
+}
+
+/// Quotes `key` unless it is a valid JavaScript identifier.
+fn property_key(key: &str) -> String {
+    let mut chars = key.chars();
+    let is_ident = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    match is_ident {
+        true => key.to_owned(),
+        false => Value::from(key).to_string(),
+    }
+}
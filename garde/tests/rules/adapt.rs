@@ -18,6 +18,16 @@ mod test_adapter {
             }
         }
     }
+
+    pub mod matches {
+        pub fn apply(v: &str, (field, value): (&str, &&str)) -> garde::Result {
+            if !v.eq_ignore_ascii_case(value) {
+                Err(garde::Error::new(format!("does not match {field}")))
+            } else {
+                Ok(())
+            }
+        }
+    }
 }
 
 #[derive(Debug, garde::Validate)]
@@ -26,6 +36,36 @@ struct Test<'a> {
     v: &'a str,
 }
 
+#[derive(Debug, garde::Validate)]
+struct Matches<'a> {
+    #[garde(skip)]
+    a: &'a str,
+    #[garde(adapt(test_adapter), matches(a))]
+    b: &'a str,
+}
+
+#[test]
+fn matches_valid() {
+    util::check_ok(
+        &[Matches {
+            a: "test",
+            b: "TEST",
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn matches_invalid() {
+    util::check_fail!(
+        &[Matches {
+            a: "test",
+            b: "tset"
+        }],
+        &()
+    )
+}
+
 #[test]
 fn alphanumeric_valid() {
     util::check_ok(&[Test { v: "test" }], &())
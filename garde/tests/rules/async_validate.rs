@@ -0,0 +1,227 @@
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context as TaskContext, Poll, Waker};
+
+use garde::AsyncValidate;
+
+/// Polls `future` to completion on the current thread.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut cx = TaskContext::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+/// Returns `Pending` once, so that every `custom_async` rule actually suspends.
+async fn yield_now() {
+    let mut yielded = false;
+    std::future::poll_fn(|_| {
+        if yielded {
+            return Poll::Ready(());
+        }
+        yielded = true;
+        Poll::Pending
+    })
+    .await
+}
+
+#[derive(Default)]
+struct Db {
+    taken: Vec<&'static str>,
+    queries: Cell<usize>,
+}
+
+async fn username_available(value: &str, db: &Db) -> garde::Result {
+    db.queries.set(db.queries.get() + 1);
+    yield_now().await;
+    if db.taken.contains(&value) {
+        return Err(garde::Error::new(format!("`{value}` is taken")));
+    }
+    Ok(())
+}
+
+async fn exists(value: &u64, _: &Db) -> garde::Result {
+    yield_now().await;
+    if *value > 100 {
+        return Err(garde::Error::new("does not exist"));
+    }
+    Ok(())
+}
+
+#[derive(Debug, garde::AsyncValidate)]
+#[garde(context(Db))]
+struct User<'a> {
+    #[garde(length(min = 3), custom_async(username_available))]
+    username: &'a str,
+    #[garde(dive)]
+    groups: Vec<Group<'a>>,
+    #[garde(dive)]
+    primary: Option<Group<'a>>,
+}
+
+#[derive(Debug, garde::AsyncValidate)]
+#[garde(context(Db))]
+struct Group<'a> {
+    #[garde(custom_async(exists))]
+    id: u64,
+    #[garde(ascii)]
+    name: &'a str,
+}
+
+#[derive(Debug, garde::AsyncValidate)]
+#[garde(context(Db))]
+enum Lookup {
+    Id(#[garde(custom_async(exists))] u64),
+    Name(#[garde(length(min = 1), custom_async(username_available))] String),
+}
+
+fn db() -> Db {
+    Db {
+        taken: vec!["admin"],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn async_validate_valid() {
+    let user = User {
+        username: "test",
+        groups: vec![Group { id: 1, name: "a" }],
+        primary: Some(Group { id: 2, name: "b" }),
+    };
+    block_on(user.validate_async_with(&db())).unwrap();
+}
+
+#[test]
+fn async_validate_invalid() {
+    let user = User {
+        username: "admin",
+        groups: vec![
+            Group { id: 1, name: "a" },
+            Group {
+                id: 101,
+                name: "😂",
+            },
+        ],
+        primary: Some(Group { id: 102, name: "b" }),
+    };
+    let report = block_on(user.validate_async_with(&db())).unwrap_err();
+    insta::assert_snapshot!(report.to_string());
+}
+
+#[test]
+fn async_validate_sync_rules_first() {
+    let db = db();
+    let user = User {
+        username: "ab",
+        groups: vec![],
+        primary: None,
+    };
+    let report = block_on(user.validate_async_with(&db)).unwrap_err();
+    let errors = report
+        .iter()
+        .map(|(path, error)| format!("{path}: {error}"))
+        .collect::<Vec<_>>();
+    assert_eq!(errors, ["username: length is lower than 3"]);
+    assert_eq!(db.queries.get(), 1);
+}
+
+#[test]
+fn async_validate_enum() {
+    let db = db();
+    block_on(Lookup::Id(1).validate_async_with(&db)).unwrap();
+    block_on(Lookup::Name("test".into()).validate_async_with(&db)).unwrap();
+    let report = block_on(Lookup::Name("admin".into()).validate_async_with(&db)).unwrap_err();
+    assert_eq!(report.to_string(), "[0]: `admin` is taken\n");
+}
+
+#[test]
+fn async_validate_is_send() {
+    fn assert_send<T: Send>(_: &T) {}
+
+    #[derive(garde::AsyncValidate)]
+    struct Test {
+        #[garde(custom_async(check))]
+        value: String,
+        #[garde(dive)]
+        nested: Vec<Nested>,
+    }
+
+    #[derive(garde::AsyncValidate)]
+    struct Nested {
+        #[garde(custom_async(check))]
+        value: String,
+    }
+
+    async fn check(_: &str, _: &()) -> garde::Result {
+        Ok(())
+    }
+
+    let test = Test {
+        value: String::new(),
+        nested: vec![],
+    };
+    let future = test.validate_async();
+    assert_send(&future);
+    block_on(future).unwrap();
+}
+
+#[derive(Debug, garde::AsyncValidate)]
+#[garde(context(Db))]
+struct UserPatch<'a> {
+    #[garde(custom_async(optional_username_available))]
+    username: Option<&'a str>,
+    #[garde(required)]
+    email: Option<&'a str>,
+}
+
+async fn optional_username_available(value: &Option<&str>, db: &Db) -> garde::Result {
+    match value {
+        Some(value) => username_available(value, db).await,
+        None => Ok(()),
+    }
+}
+
+#[test]
+fn async_validate_partial() {
+    let db = db();
+    let patch = UserPatch {
+        username: Some("test"),
+        email: None,
+    };
+    // `email` is only checked after the `custom_async` rule of `username` has suspended.
+    block_on(garde::validate::with_partial_async(
+        patch.validate_async_with(&db),
+    ))
+    .unwrap();
+    let report = block_on(patch.validate_async_with(&db)).unwrap_err();
+    assert_eq!(report.to_string(), "email: not set\n");
+}
+
+struct Update;
+
+#[derive(Debug, garde::AsyncValidate)]
+#[garde(context(Db))]
+struct UserUpdate<'a> {
+    #[garde(custom_async(username_available))]
+    username: &'a str,
+    #[garde(required, groups(Update))]
+    id: Option<u64>,
+}
+
+#[test]
+fn async_validate_group() {
+    let db = db();
+    let update = UserUpdate {
+        username: "test",
+        id: None,
+    };
+    // `id` is only checked after the `custom_async` rule of `username` has suspended.
+    let report = block_on(update.validate_group_async::<Update>(&db)).unwrap_err();
+    assert_eq!(report.to_string(), "id: not set\n");
+    block_on(update.validate_async_with(&db)).unwrap();
+}
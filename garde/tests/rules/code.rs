@@ -0,0 +1,50 @@
+use garde::Validate;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(length(min = 3), ascii)]
+    default_code: &'a str,
+    #[garde(code = "username_invalid", length(min = 3), ascii)]
+    custom_code: &'a str,
+    #[garde(code("tag_invalid"), inner(length(min = 1)))]
+    inner_code: &'a [&'a str],
+    #[garde(code = "custom_rule", custom(always_fail))]
+    custom_rule: &'a str,
+    #[garde(custom(always_fail))]
+    custom_rule_without_code: &'a str,
+}
+
+fn always_fail(_: &str, _: &()) -> garde::Result {
+    Err(garde::Error::new("always fails"))
+}
+
+#[test]
+fn code_invalid() {
+    let report = Test {
+        default_code: "ß",
+        custom_code: "ß",
+        inner_code: &[""],
+        custom_rule: "",
+        custom_rule_without_code: "",
+    }
+    .validate()
+    .unwrap_err();
+
+    let codes = report
+        .iter()
+        .map(|(path, error)| (path.to_string(), error.code()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        codes,
+        [
+            ("custom_code".into(), Some("username_invalid")),
+            ("custom_code".into(), Some("username_invalid")),
+            ("custom_rule".into(), Some("custom_rule")),
+            ("custom_rule_without_code".into(), None),
+            ("default_code".into(), Some("ascii_invalid")),
+            ("default_code".into(), Some("length_lower_than")),
+            ("inner_code[0]".into(), Some("tag_invalid")),
+        ] as [(String, Option<&str>); 7]
+    );
+}
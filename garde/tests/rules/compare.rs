@@ -0,0 +1,179 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(skip)]
+    start: u32,
+    #[garde(skip)]
+    end: u32,
+    #[garde(skip)]
+    old: &'a str,
+
+    #[garde(greater_than(start), less_than(end))]
+    exclusive: u32,
+    #[garde(greater_than_or_equal(start), less_than_or_equal(end))]
+    inclusive: u32,
+    #[garde(not_equal(old))]
+    new: &'a str,
+
+    #[garde(inner(greater_than(start)))]
+    inner: &'a [u32],
+}
+
+#[test]
+fn compare_valid() {
+    util::check_ok(
+        &[
+            Test {
+                start: 10,
+                end: 20,
+                old: "old",
+                exclusive: 15,
+                inclusive: 15,
+                new: "new",
+                inner: &[11, 19],
+            },
+            Test {
+                start: 10,
+                end: 20,
+                old: "old",
+                exclusive: 11,
+                inclusive: 10,
+                new: "new",
+                inner: &[],
+            },
+            Test {
+                start: 10,
+                end: 20,
+                old: "old",
+                exclusive: 19,
+                inclusive: 20,
+                new: "new",
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn compare_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                start: 10,
+                end: 20,
+                old: "old",
+                exclusive: 10,
+                inclusive: 9,
+                new: "old",
+                inner: &[10],
+            },
+            Test {
+                start: 10,
+                end: 20,
+                old: "old",
+                exclusive: 20,
+                inclusive: 21,
+                new: "old",
+                inner: &[11, 5],
+            },
+        ],
+        &(),
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Optional {
+    #[garde(skip)]
+    start: Option<u32>,
+    #[garde(greater_than(start))]
+    end: Option<u32>,
+    #[garde(greater_than_or_equal(start))]
+    end_inclusive: Option<u32>,
+}
+
+#[test]
+fn compare_option_valid() {
+    util::check_ok(
+        &[
+            Optional {
+                start: Some(10),
+                end: None,
+                end_inclusive: None,
+            },
+            Optional {
+                start: None,
+                end: Some(5),
+                end_inclusive: Some(5),
+            },
+            Optional {
+                start: None,
+                end: None,
+                end_inclusive: None,
+            },
+            Optional {
+                start: Some(10),
+                end: Some(11),
+                end_inclusive: Some(10),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn compare_option_invalid() {
+    util::check_fail!(
+        &[Optional {
+            start: Some(10),
+            end: Some(10),
+            end_inclusive: Some(9),
+        }],
+        &(),
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct OptionalNotEqual {
+    #[garde(skip)]
+    old: Option<u32>,
+    #[garde(not_equal(old))]
+    new: Option<u32>,
+}
+
+#[test]
+fn not_equal_option_valid() {
+    util::check_ok(
+        &[
+            OptionalNotEqual {
+                old: None,
+                new: None,
+            },
+            OptionalNotEqual {
+                old: Some(1),
+                new: None,
+            },
+            OptionalNotEqual {
+                old: None,
+                new: Some(1),
+            },
+            OptionalNotEqual {
+                old: Some(1),
+                new: Some(2),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn not_equal_option_invalid() {
+    util::check_fail!(
+        &[OptionalNotEqual {
+            old: Some(1),
+            new: Some(1),
+        }],
+        &(),
+    )
+}
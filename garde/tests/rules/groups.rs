@@ -0,0 +1,70 @@
+use garde::Validate;
+
+use super::util;
+
+struct Create;
+struct Update;
+struct Other;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(required, groups(Update))]
+    id: Option<u64>,
+
+    #[garde(length(min = 1))]
+    #[garde(length(min = 8), groups(Create))]
+    password: &'a str,
+
+    #[garde(ascii, groups(Create, Update))]
+    name: &'a str,
+
+    #[garde(dive)]
+    inner: Inner<'a>,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Inner<'a> {
+    #[garde(length(max = 3), groups(Create))]
+    value: &'a str,
+}
+
+const INVALID: Test<'static> = Test {
+    id: None,
+    password: "short",
+    name: "ß",
+    inner: Inner { value: "long" },
+};
+
+#[test]
+fn groups_valid() {
+    util::check_ok(&[INVALID], &());
+}
+
+#[test]
+fn groups_create_invalid() {
+    garde::with_group::<Create, _>(|| util::check_fail!(&[INVALID], &()));
+}
+
+#[test]
+fn groups_update_invalid() {
+    garde::with_group::<Update, _>(|| util::check_fail!(&[INVALID], &()));
+}
+
+#[test]
+fn groups_validate_group() {
+    assert!(INVALID.validate_group::<Other>().is_ok());
+    let report = INVALID.validate_group::<Update>().unwrap_err();
+    assert_eq!(report.iter().count(), 2);
+    // The group is only active for the duration of the call.
+    assert!(INVALID.validate().is_ok());
+}
+
+#[test]
+fn groups_password_empty() {
+    let test = Test {
+        password: "",
+        ..INVALID
+    };
+    let report = test.validate_group::<Update>().unwrap_err();
+    assert_eq!(report.iter().count(), 3);
+}
@@ -0,0 +1,144 @@
+use garde::http::{FieldMap, JsonApi, ProblemDetails};
+use garde::Validate as _;
+use serde_json::json;
+
+#[derive(Debug, garde::Validate)]
+#[garde(custom(always_fails))]
+struct Order<'a> {
+    #[garde(length(min = 3), ascii)]
+    name: &'a str,
+    #[garde(dive)]
+    items: Vec<Item<'a>>,
+    #[garde(rename = "ä ~", length(min = 1))]
+    note: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Item<'a> {
+    #[garde(rename = "sku/id", alphanumeric)]
+    sku: &'a str,
+}
+
+fn always_fails(_: &Order, _: &()) -> garde::Result {
+    Err(garde::Error::new("order is invalid"))
+}
+
+fn report() -> garde::Report {
+    Order {
+        name: "ß",
+        items: vec![Item { sku: "ok" }, Item { sku: "a-b" }],
+        note: "",
+    }
+    .validate()
+    .unwrap_err()
+}
+
+#[test]
+fn problem_details() {
+    let report = report();
+    assert_eq!(
+        serde_json::to_value(ProblemDetails::new(&report)).unwrap(),
+        json!({
+            "title": "Unprocessable Content",
+            "status": 422,
+            "errors": [
+                { "detail": "order is invalid", "pointer": "#" },
+                {
+                    "detail": "not alphanumeric",
+                    "pointer": "#/items/1/sku~1id",
+                    "code": "alphanumeric_invalid",
+                },
+                { "detail": "not ascii", "pointer": "#/name", "code": "ascii_invalid" },
+                {
+                    "detail": "length is lower than 3",
+                    "pointer": "#/name",
+                    "code": "length_lower_than",
+                    "params": { "min": 3 },
+                },
+                {
+                    "detail": "length is lower than 1",
+                    "pointer": "#/%C3%A4%20~0",
+                    "code": "length_lower_than",
+                    "params": { "min": 1 },
+                },
+            ],
+        })
+    );
+
+    let problem = ProblemDetails::new(&report)
+        .with_type("https://example.com/problems/validation")
+        .with_title("Your request is not valid")
+        .with_status(400)
+        .with_detail("the order could not be placed")
+        .with_instance("/orders/1");
+    let value = serde_json::to_value(problem).unwrap();
+    assert_eq!(value["type"], "https://example.com/problems/validation");
+    assert_eq!(value["title"], "Your request is not valid");
+    assert_eq!(value["status"], 400);
+    assert_eq!(value["detail"], "the order could not be placed");
+    assert_eq!(value["instance"], "/orders/1");
+    assert_eq!(value["errors"].as_array().unwrap().len(), 5);
+}
+
+#[test]
+fn json_api() {
+    let report = report();
+    assert_eq!(
+        serde_json::to_value(JsonApi::new(&report)).unwrap(),
+        json!({
+            "errors": [
+                {
+                    "status": "422",
+                    "detail": "order is invalid",
+                    "source": { "pointer": "/data/attributes" },
+                },
+                {
+                    "status": "422",
+                    "code": "alphanumeric_invalid",
+                    "detail": "not alphanumeric",
+                    "source": { "pointer": "/data/attributes/items/1/sku~1id" },
+                },
+                {
+                    "status": "422",
+                    "code": "ascii_invalid",
+                    "detail": "not ascii",
+                    "source": { "pointer": "/data/attributes/name" },
+                },
+                {
+                    "status": "422",
+                    "code": "length_lower_than",
+                    "detail": "length is lower than 3",
+                    "source": { "pointer": "/data/attributes/name" },
+                    "meta": { "min": 3 },
+                },
+                {
+                    "status": "422",
+                    "code": "length_lower_than",
+                    "detail": "length is lower than 1",
+                    "source": { "pointer": "/data/attributes/ä ~0" },
+                    "meta": { "min": 1 },
+                },
+            ],
+        })
+    );
+
+    let value =
+        serde_json::to_value(JsonApi::new(&report).with_prefix("").with_status(400)).unwrap();
+    assert_eq!(value["errors"][1]["status"], "400");
+    assert_eq!(value["errors"][1]["source"]["pointer"], "/items/1/sku~1id");
+    assert_eq!(value["errors"][0]["source"]["pointer"], "");
+}
+
+#[test]
+fn field_map() {
+    let report = report();
+    let json = serde_json::to_string(&FieldMap(&report)).unwrap();
+    assert_eq!(
+        json,
+        r#"{"":["order is invalid"],"items[1].sku/id":["not alphanumeric"],"name":["not ascii","length is lower than 3"],"ä ~":["length is lower than 1"]}"#
+    );
+    assert_eq!(
+        serde_json::to_string(&FieldMap(&garde::Report::new())).unwrap(),
+        "{}"
+    );
+}
@@ -60,6 +60,26 @@ impl garde::I18n for TestI18n {
         format!("custom: must match {field}").into()
     }
 
+    fn not_equal_field_match(&self, field: &dyn Display) -> Cow<'static, str> {
+        format!("custom: must differ from {field}").into()
+    }
+
+    fn compare_not_greater_than(&self, field: &dyn Display) -> Cow<'static, str> {
+        format!("custom: must be greater than {field}").into()
+    }
+
+    fn compare_not_greater_than_or_equal(&self, field: &dyn Display) -> Cow<'static, str> {
+        format!("custom: must be at least {field}").into()
+    }
+
+    fn compare_not_less_than(&self, field: &dyn Display) -> Cow<'static, str> {
+        format!("custom: must be less than {field}").into()
+    }
+
+    fn compare_not_less_than_or_equal(&self, field: &dyn Display) -> Cow<'static, str> {
+        format!("custom: must be at most {field}").into()
+    }
+
     fn email_invalid(&self, reason: InvalidEmail) -> Cow<'static, str> {
         format!("custom: bad email - {reason:?}").into()
     }
@@ -75,6 +95,14 @@ impl garde::I18n for TestI18n {
     fn required_not_set(&self) -> Cow<'static, str> {
         "custom: this field is required".into()
     }
+
+    fn required_with_not_set(&self, field: &dyn Display) -> Cow<'static, str> {
+        format!("custom: this field is required along with {field}").into()
+    }
+
+    fn required_without_not_set(&self, field: &dyn Display) -> Cow<'static, str> {
+        format!("custom: this field is required when {field} is missing").into()
+    }
 }
 
 #[derive(Debug, garde::Validate)]
@@ -132,6 +160,13 @@ fn test_default_i18n_messages() {
     insta::assert_snapshot!(default.ascii_invalid());
     insta::assert_snapshot!(default.alphanumeric_invalid());
     insta::assert_snapshot!(default.required_not_set());
+    insta::assert_snapshot!(default.not_equal_field_match(&"old_password"));
+    insta::assert_snapshot!(default.compare_not_greater_than(&"start"));
+    insta::assert_snapshot!(default.compare_not_greater_than_or_equal(&"start"));
+    insta::assert_snapshot!(default.compare_not_less_than(&"end"));
+    insta::assert_snapshot!(default.compare_not_less_than_or_equal(&"end"));
+    insta::assert_snapshot!(default.required_with_not_set(&"vat_id"));
+    insta::assert_snapshot!(default.required_without_not_set(&"email"));
 }
 
 #[test]
@@ -157,6 +192,13 @@ fn test_custom_i18n_messages() {
     insta::assert_snapshot!(custom.ascii_invalid());
     insta::assert_snapshot!(custom.alphanumeric_invalid());
     insta::assert_snapshot!(custom.required_not_set());
+    insta::assert_snapshot!(custom.not_equal_field_match(&"old_password"));
+    insta::assert_snapshot!(custom.compare_not_greater_than(&"start"));
+    insta::assert_snapshot!(custom.compare_not_greater_than_or_equal(&"start"));
+    insta::assert_snapshot!(custom.compare_not_less_than(&"end"));
+    insta::assert_snapshot!(custom.compare_not_less_than_or_equal(&"end"));
+    insta::assert_snapshot!(custom.required_with_not_set(&"vat_id"));
+    insta::assert_snapshot!(custom.required_without_not_set(&"email"));
 }
 
 #[test]
@@ -244,6 +286,8 @@ fn first_error_message<T: garde::Validate<Context = ()>>(v: &T) -> String {
 }
 
 /// `length_lower_than` is overridden; everything else forwards to `DefaultI18n`.
+///
+/// Methods added after the initial release are left out, and use their default implementations.
 struct LengthOnly(&'static str);
 
 impl garde::I18n for LengthOnly {
@@ -300,6 +344,99 @@ impl garde::I18n for LengthOnly {
     }
 }
 
+/// `required_not_set` is overridden; everything else forwards to `DefaultI18n`,
+/// or uses the default implementation.
+struct RequiredOnly(&'static str);
+
+impl garde::I18n for RequiredOnly {
+    fn length_lower_than(&self, m: usize) -> Cow<'static, str> {
+        garde::i18n::DefaultI18n.length_lower_than(m)
+    }
+    fn length_greater_than(&self, m: usize) -> Cow<'static, str> {
+        garde::i18n::DefaultI18n.length_greater_than(m)
+    }
+    fn range_lower_than(&self, x: &dyn Display) -> Cow<'static, str> {
+        garde::i18n::DefaultI18n.range_lower_than(x)
+    }
+    fn range_greater_than(&self, x: &dyn Display) -> Cow<'static, str> {
+        garde::i18n::DefaultI18n.range_greater_than(x)
+    }
+    fn credit_card_invalid(&self, r: InvalidCreditCard) -> Cow<'static, str> {
+        garde::i18n::DefaultI18n.credit_card_invalid(r)
+    }
+    fn pattern_no_match(&self, x: &dyn Display) -> Cow<'static, str> {
+        garde::i18n::DefaultI18n.pattern_no_match(x)
+    }
+    fn contains_missing(&self, x: &dyn Display) -> Cow<'static, str> {
+        garde::i18n::DefaultI18n.contains_missing(x)
+    }
+    fn url_invalid(&self, r: InvalidUrl) -> Cow<'static, str> {
+        garde::i18n::DefaultI18n.url_invalid(r)
+    }
+    fn prefix_missing(&self, x: &dyn Display) -> Cow<'static, str> {
+        garde::i18n::DefaultI18n.prefix_missing(x)
+    }
+    fn suffix_missing(&self, x: &dyn Display) -> Cow<'static, str> {
+        garde::i18n::DefaultI18n.suffix_missing(x)
+    }
+    fn phone_number_invalid(&self, r: InvalidPhoneNumber) -> Cow<'static, str> {
+        garde::i18n::DefaultI18n.phone_number_invalid(r)
+    }
+    fn ip_invalid(&self, k: IpKind) -> Cow<'static, str> {
+        garde::i18n::DefaultI18n.ip_invalid(k)
+    }
+    fn matches_field_mismatch(&self, x: &dyn Display) -> Cow<'static, str> {
+        garde::i18n::DefaultI18n.matches_field_mismatch(x)
+    }
+    fn email_invalid(&self, r: InvalidEmail) -> Cow<'static, str> {
+        garde::i18n::DefaultI18n.email_invalid(r)
+    }
+    fn ascii_invalid(&self) -> Cow<'static, str> {
+        garde::i18n::DefaultI18n.ascii_invalid()
+    }
+    fn alphanumeric_invalid(&self) -> Cow<'static, str> {
+        garde::i18n::DefaultI18n.alphanumeric_invalid()
+    }
+    fn required_not_set(&self) -> Cow<'static, str> {
+        format!("{}:required", self.0).into()
+    }
+}
+
+#[derive(garde::Validate)]
+struct Range {
+    #[garde(skip)]
+    start: u32,
+    #[garde(not_equal(start), greater_than(start))]
+    end: u32,
+}
+
+#[test]
+fn test_default_methods_use_english() {
+    let range = Range { start: 2, end: 2 };
+    let report = garde::with_i18n(LengthOnly("short"), || {
+        garde::Validate::validate(&range).unwrap_err()
+    });
+    assert_eq!(
+        report.to_string(),
+        "end: matches start field\nend: not greater than start field\n"
+    );
+
+    use garde::I18n as _;
+    let i18n = LengthOnly("short");
+    assert_eq!(
+        i18n.required_with_not_set(&"vat_id"),
+        "not set, but vat_id field is set"
+    );
+    assert_eq!(
+        i18n.required_without_not_set(&"email"),
+        "not set, and neither is email field"
+    );
+    assert_eq!(
+        RequiredOnly("short").required_if_not_set(),
+        "short:required"
+    );
+}
+
 #[test]
 fn test_nesting_restores_outer_handler() {
     let v = TooShort { s: "ab".into() };
@@ -381,6 +518,21 @@ fn test_borrowed_handler() {
         fn matches_field_mismatch(&self, x: &dyn Display) -> Cow<'static, str> {
             garde::i18n::DefaultI18n.matches_field_mismatch(x)
         }
+        fn not_equal_field_match(&self, x: &dyn Display) -> Cow<'static, str> {
+            garde::i18n::DefaultI18n.not_equal_field_match(x)
+        }
+        fn compare_not_greater_than(&self, x: &dyn Display) -> Cow<'static, str> {
+            garde::i18n::DefaultI18n.compare_not_greater_than(x)
+        }
+        fn compare_not_greater_than_or_equal(&self, x: &dyn Display) -> Cow<'static, str> {
+            garde::i18n::DefaultI18n.compare_not_greater_than_or_equal(x)
+        }
+        fn compare_not_less_than(&self, x: &dyn Display) -> Cow<'static, str> {
+            garde::i18n::DefaultI18n.compare_not_less_than(x)
+        }
+        fn compare_not_less_than_or_equal(&self, x: &dyn Display) -> Cow<'static, str> {
+            garde::i18n::DefaultI18n.compare_not_less_than_or_equal(x)
+        }
         fn email_invalid(&self, r: InvalidEmail) -> Cow<'static, str> {
             garde::i18n::DefaultI18n.email_invalid(r)
         }
@@ -393,6 +545,12 @@ fn test_borrowed_handler() {
         fn required_not_set(&self) -> Cow<'static, str> {
             garde::i18n::DefaultI18n.required_not_set()
         }
+        fn required_with_not_set(&self, x: &dyn Display) -> Cow<'static, str> {
+            garde::i18n::DefaultI18n.required_with_not_set(x)
+        }
+        fn required_without_not_set(&self, x: &dyn Display) -> Cow<'static, str> {
+            garde::i18n::DefaultI18n.required_without_not_set(x)
+        }
     }
 
     let b = Borrowed(prefix);
@@ -401,3 +559,285 @@ fn test_borrowed_handler() {
         assert_eq!(first_error_message(&v), "[cs-CZ] 5");
     });
 }
+
+#[test]
+fn test_validate_with_i18n() {
+    use garde::Validate as _;
+
+    let v = TooShort { s: "ab".into() };
+    let report = v.validate_with_i18n(&(), &TestI18n).unwrap_err();
+    assert_eq!(
+        report.to_string(),
+        "s: custom: too short, need at least 5\n"
+    );
+
+    // The handler is only used for that call.
+    assert_eq!(first_error_message(&v), "length is lower than 5");
+
+    let report = garde::Unvalidated::new(v)
+        .validate_with_i18n(&(), &LengthOnly("EXPLICIT"))
+        .unwrap_err();
+    assert_eq!(report.to_string(), "s: EXPLICIT:5\n");
+}
+
+#[test]
+fn test_validate_async_with_i18n() {
+    use std::future::Future;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    use garde::AsyncValidate as _;
+
+    async fn suspend(_: &str, _: &()) -> garde::Result {
+        let mut suspended = false;
+        std::future::poll_fn(|_| match std::mem::replace(&mut suspended, true) {
+            true => Poll::Ready(()),
+            false => Poll::Pending,
+        })
+        .await;
+        Ok(())
+    }
+
+    #[derive(garde::AsyncValidate)]
+    struct Test {
+        #[garde(custom_async(suspend))]
+        first: String,
+        #[garde(dive)]
+        nested: Nested,
+    }
+
+    #[derive(garde::AsyncValidate)]
+    struct Nested {
+        #[garde(ascii)]
+        value: String,
+    }
+
+    let test = Test {
+        first: String::new(),
+        nested: Nested {
+            value: "😂".into()
+        },
+    };
+    let mut future = pin!(test.validate_async_with_i18n(&(), &TestI18n));
+    let mut cx = Context::from_waker(Waker::noop());
+    assert!(future.as_mut().poll(&mut cx).is_pending());
+
+    // Other work on the same thread between polls does not see the handler.
+    let v = TooShort { s: "ab".into() };
+    assert_eq!(first_error_message(&v), "length is lower than 5");
+
+    let Poll::Ready(result) = future.as_mut().poll(&mut cx) else {
+        panic!("expected the future to be ready");
+    };
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "nested.value: custom: contains non-ASCII characters\n"
+    );
+}
+
+#[test]
+fn test_report_localize() {
+    use garde::Validate as _;
+
+    fn always_fails(_: &str, _: &()) -> garde::Result {
+        Err(garde::Error::new("custom"))
+    }
+
+    #[derive(garde::Validate)]
+    struct Test {
+        #[garde(length(min = 3), code = "name_too_short")]
+        name: String,
+        #[garde(range(max = 120))]
+        age: u32,
+        #[garde(ipv4)]
+        ip: String,
+        #[garde(email)]
+        email: String,
+        #[garde(ascii(message = "plain text only"))]
+        bio: String,
+        #[garde(custom(always_fails))]
+        custom: String,
+    }
+
+    let test = Test {
+        name: "ab".into(),
+        age: 150,
+        ip: "::1".into(),
+        email: "invalid".into(),
+        bio: "😂".into(),
+        custom: String::new(),
+    };
+    let report = test.validate().unwrap_err();
+    let custom = report.localize(&TestI18n);
+
+    assert_eq!(
+        custom.to_string(),
+        "age: custom: value too large, maximum is 120\n\
+         bio: plain text only\n\
+         custom: custom\n\
+         email: custom: bad email - MissingAt\n\
+         ip: custom: not a valid IPv4 IP\n\
+         name: custom: too short, need at least 3\n"
+    );
+    // The original report is unchanged, and can be localized again.
+    assert_eq!(
+        report.localize(&garde::i18n::DefaultI18n).to_string(),
+        report.to_string()
+    );
+
+    let (_, name) = custom
+        .iter()
+        .find(|(path, _)| path.to_string() == "name")
+        .unwrap();
+    assert_eq!(name.code(), Some("name_too_short"));
+    assert_eq!(name.rule(), Some("length_lower_than"));
+    assert_eq!(
+        name.params().get("min").and_then(|min| min.as_int()),
+        Some(3)
+    );
+}
+
+struct CustomKeys;
+
+impl garde::I18n for CustomKeys {
+    fn length_lower_than(&self, min: usize) -> Cow<'static, str> {
+        TestI18n.length_lower_than(min)
+    }
+    fn length_greater_than(&self, m: usize) -> Cow<'static, str> {
+        TestI18n.length_greater_than(m)
+    }
+    fn range_lower_than(&self, x: &dyn Display) -> Cow<'static, str> {
+        TestI18n.range_lower_than(x)
+    }
+    fn range_greater_than(&self, x: &dyn Display) -> Cow<'static, str> {
+        TestI18n.range_greater_than(x)
+    }
+    fn credit_card_invalid(&self, r: InvalidCreditCard) -> Cow<'static, str> {
+        TestI18n.credit_card_invalid(r)
+    }
+    fn pattern_no_match(&self, x: &dyn Display) -> Cow<'static, str> {
+        TestI18n.pattern_no_match(x)
+    }
+    fn contains_missing(&self, x: &dyn Display) -> Cow<'static, str> {
+        TestI18n.contains_missing(x)
+    }
+    fn url_invalid(&self, r: InvalidUrl) -> Cow<'static, str> {
+        TestI18n.url_invalid(r)
+    }
+    fn prefix_missing(&self, x: &dyn Display) -> Cow<'static, str> {
+        TestI18n.prefix_missing(x)
+    }
+    fn suffix_missing(&self, x: &dyn Display) -> Cow<'static, str> {
+        TestI18n.suffix_missing(x)
+    }
+    fn phone_number_invalid(&self, r: InvalidPhoneNumber) -> Cow<'static, str> {
+        TestI18n.phone_number_invalid(r)
+    }
+    fn ip_invalid(&self, k: IpKind) -> Cow<'static, str> {
+        TestI18n.ip_invalid(k)
+    }
+    fn matches_field_mismatch(&self, x: &dyn Display) -> Cow<'static, str> {
+        TestI18n.matches_field_mismatch(x)
+    }
+    fn not_equal_field_match(&self, x: &dyn Display) -> Cow<'static, str> {
+        TestI18n.not_equal_field_match(x)
+    }
+    fn compare_not_greater_than(&self, x: &dyn Display) -> Cow<'static, str> {
+        TestI18n.compare_not_greater_than(x)
+    }
+    fn compare_not_greater_than_or_equal(&self, x: &dyn Display) -> Cow<'static, str> {
+        TestI18n.compare_not_greater_than_or_equal(x)
+    }
+    fn compare_not_less_than(&self, x: &dyn Display) -> Cow<'static, str> {
+        TestI18n.compare_not_less_than(x)
+    }
+    fn compare_not_less_than_or_equal(&self, x: &dyn Display) -> Cow<'static, str> {
+        TestI18n.compare_not_less_than_or_equal(x)
+    }
+    fn email_invalid(&self, r: InvalidEmail) -> Cow<'static, str> {
+        TestI18n.email_invalid(r)
+    }
+    fn ascii_invalid(&self) -> Cow<'static, str> {
+        TestI18n.ascii_invalid()
+    }
+    fn alphanumeric_invalid(&self) -> Cow<'static, str> {
+        TestI18n.alphanumeric_invalid()
+    }
+    fn required_not_set(&self) -> Cow<'static, str> {
+        TestI18n.required_not_set()
+    }
+    fn required_with_not_set(&self, x: &dyn Display) -> Cow<'static, str> {
+        TestI18n.required_with_not_set(x)
+    }
+    fn required_without_not_set(&self, x: &dyn Display) -> Cow<'static, str> {
+        TestI18n.required_without_not_set(x)
+    }
+    fn custom(&self, key: &str, params: &garde::error::Params) -> Option<Cow<'static, str>> {
+        match key {
+            "username_reserved" => {
+                let username = params.get("username")?;
+                Some(format!("custom: `{username}` is reserved").into())
+            }
+            _ => None,
+        }
+    }
+}
+
+fn not_reserved(value: &str, _: &()) -> garde::Result {
+    if value == "admin" {
+        return Err(
+            garde::Error::from_key("username_reserved", "username is reserved")
+                .with_param("username", value),
+        );
+    }
+    Ok(())
+}
+
+fn not_empty(value: &str, _: &()) -> garde::Result {
+    if value.is_empty() {
+        return Err(garde::Error::from_key("value_empty", "must not be empty"));
+    }
+    Ok(())
+}
+
+#[derive(Debug, garde::Validate)]
+struct Account {
+    #[garde(custom(not_reserved))]
+    username: String,
+    #[garde(custom(not_empty))]
+    display_name: String,
+}
+
+#[test]
+fn test_custom_keys() {
+    use garde::Validate as _;
+
+    let account = Account {
+        username: "admin".into(),
+        display_name: String::new(),
+    };
+
+    let report = account.validate().unwrap_err();
+    assert_eq!(
+        report.to_string(),
+        "display_name: must not be empty\nusername: username is reserved\n"
+    );
+    let (_, username) = report.iter().nth(1).unwrap();
+    assert_eq!(username.code(), Some("username_reserved"));
+    assert_eq!(username.rule(), Some("username_reserved"));
+
+    // Keys without a translation keep their default message.
+    let expected = "display_name: must not be empty\nusername: custom: `admin` is reserved\n";
+    let report = garde::with_i18n(CustomKeys, || account.validate()).unwrap_err();
+    assert_eq!(report.to_string(), expected);
+
+    let report = account.validate().unwrap_err().localize(&CustomKeys);
+    assert_eq!(report.to_string(), expected);
+
+    // Localizing again with a handler without translations restores the default message.
+    let report = report.localize(&garde::i18n::DefaultI18n);
+    assert_eq!(
+        report.to_string(),
+        "display_name: must not be empty\nusername: username is reserved\n"
+    );
+}
@@ -0,0 +1,187 @@
+use garde::i18n::{FluentI18n, FluentI18nError, InvalidEmail, IpKind};
+use garde::{I18n as _, Validate as _};
+
+const FTL: &str = r#"
+length_lower_than = musí obsahovat alespoň { $min } znaky
+range_greater_than = nesmí být větší než { $max }
+email_invalid = { $reason ->
+    [missing_at] chybí zavináč
+   *[other] neplatný email
+}
+ip_invalid = neplatná { $kind ->
+    [ipv4] IPv4
+    [ipv6] IPv6
+   *[ip] IP
+} adresa
+matches_field_mismatch = neodpovídá poli { $field }
+required_not_set = povinné pole
+"#;
+
+fn i18n() -> FluentI18n {
+    FluentI18n::from_ftl("cs".parse().unwrap(), FTL).unwrap()
+}
+
+#[derive(Debug, garde::Validate)]
+struct User {
+    #[garde(length(min = 3))]
+    name: String,
+    #[garde(range(max = 120))]
+    age: u8,
+    #[garde(email)]
+    email: String,
+    #[garde(required)]
+    phone: Option<String>,
+    #[garde(ascii)]
+    nickname: String,
+}
+
+#[test]
+fn fluent_messages() {
+    let i18n = i18n();
+    assert_eq!(i18n.length_lower_than(3), "musí obsahovat alespoň 3 znaky");
+    assert_eq!(i18n.range_greater_than(&120), "nesmí být větší než 120");
+    assert_eq!(i18n.email_invalid(InvalidEmail::MissingAt), "chybí zavináč");
+    assert_eq!(i18n.email_invalid(InvalidEmail::Empty), "neplatný email");
+    assert_eq!(i18n.ip_invalid(IpKind::V6), "neplatná IPv6 adresa");
+    assert_eq!(
+        i18n.matches_field_mismatch(&"password"),
+        "neodpovídá poli password"
+    );
+    assert_eq!(i18n.required_not_set(), "povinné pole");
+    assert_eq!(i18n.required_if_not_set(), "povinné pole");
+}
+
+#[test]
+fn fluent_falls_back_to_default() {
+    let i18n = i18n();
+    assert_eq!(i18n.length_greater_than(5), "length is greater than 5");
+    assert_eq!(i18n.ascii_invalid(), "not ascii");
+    assert_eq!(
+        i18n.required_with_not_set(&"vat_id"),
+        "not set, but vat_id field is set"
+    );
+}
+
+#[test]
+fn fluent_numbers_can_be_selected() {
+    let i18n = FluentI18n::from_ftl(
+        "en".parse().unwrap(),
+        r#"
+range_lower_than = { $min ->
+    [one] must be at least one
+   *[other] must be at least { $min }
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(i18n.range_lower_than(&1), "must be at least one");
+    assert_eq!(i18n.range_lower_than(&2.5), "must be at least 2.5");
+}
+
+#[test]
+fn fluent_format_errors_fall_back_to_default() {
+    let i18n = FluentI18n::from_ftl(
+        "en".parse().unwrap(),
+        "length_lower_than = at least { $minimum } characters",
+    )
+    .unwrap();
+    assert_eq!(i18n.length_lower_than(3), "length is lower than 3");
+}
+
+#[test]
+fn fluent_validation() {
+    let user = User {
+        name: "Jo".into(),
+        age: 150,
+        email: "invalid".into(),
+        phone: None,
+        nickname: "Jožka".into(),
+    };
+    let report = garde::with_i18n(i18n(), || user.validate()).unwrap_err();
+    let errors = report
+        .iter()
+        .map(|(path, error)| format!("{path}: {error} [{}]", error.code().unwrap_or_default()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        [
+            "age: nesmí být větší než 120 [range_greater_than]",
+            "email: chybí zavináč [email_invalid]",
+            "name: musí obsahovat alespoň 3 znaky [length_lower_than]",
+            "nickname: not ascii [ascii_invalid]",
+            "phone: povinné pole [required_not_set]",
+        ]
+    );
+}
+
+#[test]
+fn fluent_load_errors() {
+    let mut i18n = i18n();
+    assert!(matches!(
+        i18n.add_ftl("required_not_set = znovu"),
+        Err(FluentI18nError::Resource(_))
+    ));
+    assert!(matches!(
+        FluentI18n::from_ftl("cs".parse().unwrap(), "length_lower_than = { $min"),
+        Err(FluentI18nError::Parse(_))
+    ));
+}
+
+#[test]
+fn fluent_custom_keys() {
+    let mut i18n = i18n();
+    i18n.add_ftl("username_reserved = uživatelské jméno { $username } je rezervované")
+        .unwrap();
+
+    let error = garde::with_i18n(&i18n, || {
+        garde::Error::from_key("username_reserved", "username is reserved")
+            .with_param("username", "admin")
+    });
+    assert_eq!(error.message(), "uživatelské jméno admin je rezervované");
+
+    let error = garde::with_i18n(&i18n, || {
+        garde::Error::from_key("unknown_key", "default message")
+    });
+    assert_eq!(error.message(), "default message");
+}
+
+#[derive(Debug, garde::Validate)]
+struct SignUp {
+    #[garde(skip, label = "Password")]
+    password: String,
+    #[garde(matches(password))]
+    password_confirmation: String,
+}
+
+#[test]
+fn fluent_labels() {
+    let sign_up = SignUp {
+        password: "hunter2".into(),
+        password_confirmation: "hunter3".into(),
+    };
+
+    // Without a translation, the `label` attribute is used.
+    let report = garde::with_i18n(i18n(), || sign_up.validate()).unwrap_err();
+    assert_eq!(
+        report.to_string(),
+        "password_confirmation: neodpovídá poli Password\n"
+    );
+
+    let mut i18n = i18n();
+    i18n.add_ftl("label_password = heslo").unwrap();
+    let report = garde::with_i18n(&i18n, || sign_up.validate()).unwrap_err();
+    assert_eq!(
+        report.to_string(),
+        "password_confirmation: neodpovídá poli heslo\n"
+    );
+
+    let report = sign_up.validate().unwrap_err();
+    assert_eq!(
+        report.to_string(),
+        "password_confirmation: does not match Password field\n"
+    );
+    assert_eq!(
+        report.localize(&i18n).to_string(),
+        "password_confirmation: neodpovídá poli heslo\n"
+    );
+}
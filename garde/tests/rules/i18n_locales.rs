@@ -0,0 +1,170 @@
+use std::fmt::Write as _;
+
+use garde::i18n::{
+    locales, DefaultI18n, FluentI18n, InvalidCreditCard, InvalidEmail, InvalidPhoneNumber,
+    InvalidUrl, IpKind,
+};
+use garde::I18n;
+
+const LOCALES: &[&str] = &["cs", "de", "es", "fr", "ja", "pt"];
+
+const MESSAGES: &[&str] = &[
+    "length_lower_than",
+    "length_greater_than",
+    "range_lower_than",
+    "range_greater_than",
+    "credit_card_invalid",
+    "pattern_no_match",
+    "contains_missing",
+    "url_invalid",
+    "prefix_missing",
+    "suffix_missing",
+    "phone_number_invalid",
+    "ip_invalid",
+    "matches_field_mismatch",
+    "not_equal_field_match",
+    "compare_not_greater_than",
+    "compare_not_greater_than_or_equal",
+    "compare_not_less_than",
+    "compare_not_less_than_or_equal",
+    "email_invalid",
+    "ascii_invalid",
+    "alphanumeric_invalid",
+    "required_not_set",
+    "required_with_not_set",
+    "required_without_not_set",
+];
+
+const CREDIT_CARD: &[InvalidCreditCard] = &[
+    InvalidCreditCard::InvalidFormat,
+    InvalidCreditCard::InvalidLength,
+    InvalidCreditCard::InvalidLuhn,
+    InvalidCreditCard::UnknownType,
+    InvalidCreditCard::Other,
+];
+
+const URL: &[InvalidUrl] = &[
+    InvalidUrl::EmptyHost,
+    InvalidUrl::IdnaError,
+    InvalidUrl::InvalidPort,
+    InvalidUrl::InvalidIpv4Address,
+    InvalidUrl::InvalidIpv6Address,
+    InvalidUrl::InvalidDomainCharacter,
+    InvalidUrl::RelativeUrlWithoutBase,
+    InvalidUrl::RelativeUrlWithCannotBeABaseBase,
+    InvalidUrl::SetHostOnCannotBeABaseUrl,
+    InvalidUrl::Overflow,
+    InvalidUrl::Other,
+];
+
+const PHONE_NUMBER: &[InvalidPhoneNumber] = &[
+    InvalidPhoneNumber::Invalid,
+    InvalidPhoneNumber::NotANumber,
+    InvalidPhoneNumber::InvalidCountryCode,
+    InvalidPhoneNumber::TooShortAfterIdd,
+    InvalidPhoneNumber::TooShortNsn,
+    InvalidPhoneNumber::TooLong,
+    InvalidPhoneNumber::MalformedInteger,
+    InvalidPhoneNumber::Other,
+];
+
+const EMAIL: &[InvalidEmail] = &[
+    InvalidEmail::Empty,
+    InvalidEmail::MissingAt,
+    InvalidEmail::UserLengthExceeded,
+    InvalidEmail::InvalidUser,
+    InvalidEmail::DomainLengthExceeded,
+    InvalidEmail::InvalidDomain,
+];
+
+const IP: &[IpKind] = &[IpKind::Any, IpKind::V4, IpKind::V6];
+
+/// Every message of `i18n`, one per line, including every reason and kind.
+fn messages(i18n: &dyn I18n) -> Vec<String> {
+    let mut messages = vec![
+        i18n.length_lower_than(3).into_owned(),
+        i18n.length_greater_than(25).into_owned(),
+        i18n.range_lower_than(&18).into_owned(),
+        i18n.range_greater_than(&120).into_owned(),
+        i18n.pattern_no_match(&"^[a-z]+$").into_owned(),
+        i18n.contains_missing(&"foo").into_owned(),
+        i18n.prefix_missing(&"https://").into_owned(),
+        i18n.suffix_missing(&".com").into_owned(),
+        i18n.matches_field_mismatch(&"password").into_owned(),
+        i18n.not_equal_field_match(&"password").into_owned(),
+        i18n.compare_not_greater_than(&"start").into_owned(),
+        i18n.compare_not_greater_than_or_equal(&"start")
+            .into_owned(),
+        i18n.compare_not_less_than(&"end").into_owned(),
+        i18n.compare_not_less_than_or_equal(&"end").into_owned(),
+        i18n.ascii_invalid().into_owned(),
+        i18n.alphanumeric_invalid().into_owned(),
+        i18n.required_not_set().into_owned(),
+        i18n.required_with_not_set(&"vat_id").into_owned(),
+        i18n.required_without_not_set(&"email").into_owned(),
+    ];
+    messages.extend(
+        CREDIT_CARD
+            .iter()
+            .map(|r| i18n.credit_card_invalid(*r).into_owned()),
+    );
+    messages.extend(URL.iter().map(|r| i18n.url_invalid(*r).into_owned()));
+    messages.extend(
+        PHONE_NUMBER
+            .iter()
+            .map(|r| i18n.phone_number_invalid(*r).into_owned()),
+    );
+    messages.extend(EMAIL.iter().map(|r| i18n.email_invalid(*r).into_owned()));
+    messages.extend(IP.iter().map(|k| i18n.ip_invalid(*k).into_owned()));
+    messages
+}
+
+#[test]
+fn locales_cover_every_message() {
+    let default = messages(&DefaultI18n);
+    for locale in LOCALES {
+        let i18n = FluentI18n::bundled(locale.parse().unwrap()).unwrap();
+        for id in MESSAGES {
+            assert!(
+                i18n.bundle().has_message(id),
+                "`{locale}` is missing `{id}`"
+            );
+        }
+
+        let messages = messages(&i18n);
+        for (message, english) in messages.iter().zip(&default) {
+            assert_ne!(message, english, "`{locale}` is not translated");
+            assert!(!message.contains('{'), "`{locale}`: {message}");
+        }
+        let mut unique = messages.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(
+            unique.len(),
+            messages.len(),
+            "`{locale}` has a reason without its own translation"
+        );
+    }
+}
+
+#[test]
+fn locales_snapshot() {
+    for locale in LOCALES {
+        let i18n = FluentI18n::bundled(locale.parse().unwrap()).unwrap();
+        let mut output = String::new();
+        for message in messages(&i18n) {
+            writeln!(output, "{message}").unwrap();
+        }
+        insta::assert_snapshot!(format!("locale_{locale}"), output);
+    }
+}
+
+#[test]
+fn locales_bundled() {
+    assert!(locales::source("de").is_some());
+    assert!(locales::source("xx").is_none());
+    assert!(FluentI18n::bundled("xx".parse().unwrap()).is_none());
+
+    let i18n = FluentI18n::bundled("de-AT".parse().unwrap()).unwrap();
+    assert_eq!(i18n.length_lower_than(3), "Länge ist kleiner als 3");
+}
@@ -0,0 +1,148 @@
+use garde::introspect::{FieldRules, Rule};
+use garde::Validate;
+
+struct Admin;
+
+struct Context {
+    max_bio: usize,
+}
+
+fn check_bio(_: &str, _: &Context) -> garde::Result {
+    Ok(())
+}
+
+#[derive(garde::Validate)]
+#[garde(context(Context as ctx))]
+struct User {
+    #[garde(rename = "userName", ascii, length(min = 3, max = 25))]
+    username: String,
+    #[garde(length(chars, max = ctx.max_bio), custom(check_bio))]
+    bio: String,
+    #[garde(range(equal = 18), greater_than_or_equal(age))]
+    min_age: u8,
+    #[garde(skip)]
+    age: u8,
+    #[garde(length(max = 5), inner(length(min = 1), ipv4))]
+    tags: Vec<String>,
+    #[garde(required_if(self.age > 18))]
+    phone: Option<String>,
+    #[garde(if(cond = self.age < 18, required), groups(Admin))]
+    guardian: Option<String>,
+    #[garde(dive)]
+    addresses: Vec<Address>,
+}
+
+#[derive(garde::Validate)]
+#[garde(context(Context))]
+struct Address {
+    #[garde(length(min = 1))]
+    street: String,
+}
+
+#[derive(garde::Validate)]
+#[allow(dead_code)]
+enum Shape {
+    Empty,
+    Circle(#[garde(range(min = 0.0))] f64),
+}
+
+fn field(rules: &'static [FieldRules], name: &str) -> &'static FieldRules {
+    rules
+        .iter()
+        .find(|field| field.name == name)
+        .unwrap_or_else(|| panic!("no field `{name}`"))
+}
+
+fn names(rules: &[Rule]) -> Vec<&'static str> {
+    rules.iter().map(|rule| rule.name).collect()
+}
+
+#[test]
+fn introspect_fields() {
+    let rules = User::rules();
+    let mut fields = rules.iter().map(|field| field.name).collect::<Vec<_>>();
+    fields.sort();
+    assert_eq!(
+        fields,
+        [
+            "addresses",
+            "bio",
+            "guardian",
+            "min_age",
+            "phone",
+            "tags",
+            "userName"
+        ]
+    );
+    assert!(rules.iter().all(|field| field.variant.is_none()));
+}
+
+#[test]
+fn introspect_rules() {
+    let rules = User::rules();
+
+    let username = &field(rules, "userName").rules;
+    assert_eq!(names(username.rules), ["ascii", "length::simple"]);
+    assert_eq!(username.rules[1].args, [("min", "3"), ("max", "25")]);
+
+    let bio = &field(rules, "bio").rules;
+    assert_eq!(names(bio.rules), ["length::chars"]);
+    assert_eq!(bio.rules[0].arg("max"), Some("ctx.max_bio"));
+    assert_eq!(bio.custom, ["check_bio"]);
+
+    let min_age = &field(rules, "min_age").rules;
+    assert_eq!(names(min_age.rules), ["compare", "range"]);
+    assert_eq!(min_age.rules[0].args, [("op", ">="), ("field", "age")]);
+    assert_eq!(min_age.rules[1].args, [("equal", "18")]);
+
+    let phone = &field(rules, "phone").rules;
+    assert_eq!(phone.rules[0].name, "required_if");
+    assert_eq!(phone.rules[0].arg("condition"), Some("self.age > 18"));
+}
+
+#[test]
+fn introspect_nesting() {
+    let rules = User::rules();
+
+    let tags = &field(rules, "tags").rules;
+    assert_eq!(tags.rules[0].args, [("max", "5")]);
+    let inner = tags.inner.unwrap();
+    assert_eq!(names(inner.rules), ["ip", "length::simple"]);
+    assert_eq!(inner.rules[0].arg("kind"), Some("v4"));
+
+    let guardian = &field(rules, "guardian").rules;
+    assert!(guardian.rules.is_empty());
+    assert_eq!(guardian.conditional.len(), 1);
+    let groups = &guardian.conditional[0];
+    assert!(groups.condition.contains("is_active"));
+    assert_eq!(groups.rule_set.conditional[0].condition, "self.age < 18");
+    assert_eq!(
+        names(groups.rule_set.conditional[0].rule_set.rules),
+        ["required"]
+    );
+
+    let addresses = field(rules, "addresses");
+    assert!(addresses.rules.rules.is_empty());
+    let dive = addresses.dive.unwrap()();
+    assert_eq!(dive.len(), 1);
+    assert_eq!(dive[0].name, "street");
+    assert!(field(rules, "userName").dive.is_none());
+}
+
+#[test]
+fn introspect_enum() {
+    let rules = Shape::rules();
+    assert_eq!(rules.len(), 1);
+    assert_eq!(rules[0].name, "0");
+    assert_eq!(rules[0].variant, Some("Circle"));
+    assert_eq!(rules[0].rules.rules[0].args, [("min", "0.0")]);
+}
+
+#[test]
+fn introspect_containers() {
+    assert!(<() as Validate>::rules().is_empty());
+    assert_eq!(
+        <Option<Box<Address>> as Validate>::rules()[0].name,
+        "street"
+    );
+}
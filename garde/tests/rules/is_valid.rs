@@ -0,0 +1,111 @@
+use std::cell::Cell;
+
+use garde::Validate;
+
+#[derive(Default)]
+struct Context {
+    calls: Cell<usize>,
+}
+
+fn counted(value: &str, ctx: &Context) -> garde::Result {
+    ctx.calls.set(ctx.calls.get() + 1);
+    if value.is_empty() {
+        return Err(garde::Error::new("empty"));
+    }
+    Ok(())
+}
+
+fn report_empty(value: &Test, _: &Context, reporter: &mut garde::error::Reporter) {
+    if value.names.is_empty() {
+        reporter.append(garde::Error::new("no names"));
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context))]
+struct Item<'a> {
+    #[garde(custom(counted))]
+    a: &'a str,
+    #[garde(length(min = 1), email)]
+    b: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context))]
+#[garde(report(report_empty))]
+struct Test<'a> {
+    #[garde(dive)]
+    items: Vec<Item<'a>>,
+    #[garde(inner(custom(counted), length(max = 3)))]
+    names: Vec<&'a str>,
+    #[garde(required)]
+    id: Option<u64>,
+}
+
+fn valid() -> Test<'static> {
+    Test {
+        items: vec![Item {
+            a: "a",
+            b: "a@example.com",
+        }],
+        names: vec!["abc"],
+        id: Some(1),
+    }
+}
+
+#[test]
+fn is_valid_ok() {
+    let test = valid();
+    assert!(test.is_valid());
+    assert!(test.validate().is_ok());
+}
+
+#[test]
+fn is_valid_fail_fast() {
+    let ctx = Context::default();
+    let test = Test {
+        items: (0..100).map(|_| Item { a: "", b: "" }).collect(),
+        ..valid()
+    };
+    assert!(!test.is_valid_with(&ctx));
+    assert_eq!(ctx.calls.get(), 1);
+}
+
+#[test]
+fn is_valid_matches_validate() {
+    let cases = [
+        Test {
+            items: vec![Item {
+                a: "a",
+                b: "not an email",
+            }],
+            ..valid()
+        },
+        Test {
+            names: vec!["abcd"],
+            ..valid()
+        },
+        Test {
+            names: vec![],
+            ..valid()
+        },
+        Test {
+            id: None,
+            ..valid()
+        },
+    ];
+    for case in &cases {
+        assert!(case.validate().is_err(), "{case:?}");
+        assert!(!case.is_valid(), "{case:?}");
+    }
+}
+
+#[test]
+fn is_valid_partial() {
+    let test = Test {
+        id: None,
+        ..valid()
+    };
+    assert!(!test.is_valid());
+    assert!(garde::with_partial(|| test.is_valid()));
+}
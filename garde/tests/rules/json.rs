@@ -0,0 +1,196 @@
+use serde::Deserialize;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Deserialize, garde::Validate)]
+struct User {
+    #[garde(ascii, length(min = 3))]
+    username: String,
+    #[garde(range(min = 18))]
+    age: u8,
+    #[garde(length(max = 2), dive)]
+    addresses: Vec<Address>,
+    #[garde(skip)]
+    #[serde(default)]
+    tags: Vec<String>,
+    #[garde(inner(length(min = 1)))]
+    nickname: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Deserialize, garde::Validate)]
+struct Address {
+    #[garde(length(min = 1))]
+    street: String,
+    #[garde(range(max = 99999))]
+    zip: u32,
+}
+
+#[derive(Debug, PartialEq, Deserialize, garde::Validate)]
+enum Shape {
+    Circle {
+        #[garde(range(min = 0.0))]
+        radius: f64,
+    },
+    Square(#[garde(range(min = 0))] i32),
+}
+
+fn errors(report: garde::Report) -> Vec<String> {
+    report
+        .iter()
+        .map(|(path, error)| format!("{path}: {error} [{}]", error.code().unwrap_or_default()))
+        .collect()
+}
+
+#[test]
+fn json_valid() {
+    let value = json!({
+        "username": "test",
+        "age": 20,
+        "addresses": [{ "street": "Main St", "zip": 12345 }],
+        "nickname": null,
+    });
+    assert_eq!(
+        garde::json::validate::<User>(&value).unwrap(),
+        User {
+            username: "test".into(),
+            age: 20,
+            addresses: vec![Address {
+                street: "Main St".into(),
+                zip: 12345,
+            }],
+            tags: vec![],
+            nickname: None,
+        }
+    );
+}
+
+#[test]
+fn json_type_and_rule_errors() {
+    let value = json!({
+        "username": "ab",
+        "age": 300,
+        "addresses": [
+            { "street": 5, "zip": 123456 },
+            { "street": "", "zip": "12345" },
+            { "street": "Main St" },
+        ],
+        "nickname": "",
+    });
+    assert_eq!(
+        errors(garde::json::validate::<User>(&value).unwrap_err()),
+        [
+            "age: invalid value: integer `300`, expected u8 [invalid_value]",
+            "addresses[0].street: invalid type: integer `5`, expected a string [invalid_type]",
+            "addresses[1].zip: invalid type: string \"12345\", expected u32 [invalid_type]",
+            "addresses[2].zip: missing field [missing_field]",
+            "addresses[0].zip: greater than 99999 [range_greater_than]",
+            "addresses[1].street: length is lower than 1 [length_lower_than]",
+            "addresses: length is greater than 2 [length_greater_than]",
+            "nickname: length is lower than 1 [length_lower_than]",
+            "username: length is lower than 3 [length_lower_than]",
+        ]
+    );
+}
+
+#[test]
+fn json_wrong_container_types() {
+    let value = json!({
+        "username": ["test"],
+        "age": 20.5,
+        "addresses": { "street": "Main St" },
+    });
+    assert_eq!(
+        errors(garde::json::validate::<User>(&value).unwrap_err()),
+        [
+            "username: invalid type: sequence, expected a string [invalid_type]",
+            "age: invalid type: floating point `20.5`, expected u8 [invalid_type]",
+            "addresses: invalid type: map, expected a sequence [invalid_type]",
+        ]
+    );
+
+    assert_eq!(
+        errors(garde::json::validate::<User>(&json!("user")).unwrap_err()),
+        [r#": invalid type: string "user", expected struct User [invalid_type]"#]
+    );
+}
+
+#[test]
+fn json_enum() {
+    assert_eq!(
+        garde::json::validate::<Shape>(&json!({ "Circle": { "radius": 1.0 } })).unwrap(),
+        Shape::Circle { radius: 1.0 }
+    );
+    assert_eq!(
+        errors(
+            garde::json::validate::<Shape>(&json!({ "Circle": { "radius": "big" } })).unwrap_err()
+        ),
+        [r#"radius: invalid type: string "big", expected f64 [invalid_type]"#]
+    );
+    assert_eq!(
+        errors(garde::json::validate::<Shape>(&json!({ "Square": -1 })).unwrap_err()),
+        ["[0]: lower than 0 [range_lower_than]"]
+    );
+    assert_eq!(
+        errors(garde::json::validate::<Shape>(&json!({ "Triangle": 3 })).unwrap_err()),
+        ["unknown variant `Triangle`, expected one of `Circle`, `Square` [unknown_variant]"]
+            .map(|error| format!(": {error}"))
+    );
+}
+
+#[test]
+fn json_enum_type_error_hides_rule_errors() {
+    #[derive(Debug, Deserialize, garde::Validate)]
+    #[allow(dead_code)]
+    enum Test {
+        Named {
+            #[garde(length(min = 1))]
+            name: String,
+        },
+    }
+
+    assert_eq!(
+        errors(garde::json::validate::<Test>(&json!({ "Named": { "name": 1 } })).unwrap_err()),
+        ["name: invalid type: integer `1`, expected a string [invalid_type]"]
+    );
+}
+
+#[test]
+fn json_unrecoverable_error_keeps_earlier_errors() {
+    #[derive(Debug, Deserialize, garde::Validate)]
+    #[allow(dead_code)]
+    struct Test {
+        #[garde(skip)]
+        count: u8,
+        #[garde(skip)]
+        shape: Shape,
+    }
+
+    let value = json!({ "count": "many", "shape": "Triangle" });
+    assert_eq!(
+        errors(garde::json::validate::<Test>(&value).unwrap_err()),
+        [
+            r#"count: invalid type: string "many", expected u8 [invalid_type]"#,
+            "shape: unknown variant `Triangle`, expected one of `Circle`, `Square` [unknown_variant]",
+        ]
+    );
+}
+
+#[test]
+fn json_with_context() {
+    struct Context {
+        min: usize,
+    }
+
+    #[derive(Debug, Deserialize, garde::Validate)]
+    #[garde(context(Context as ctx))]
+    struct Test {
+        #[garde(length(min = ctx.min))]
+        value: String,
+    }
+
+    let value = json!({ "value": "abc" });
+    assert!(garde::json::validate_with::<Test>(&value, &Context { min: 3 }).is_ok());
+    assert_eq!(
+        errors(garde::json::validate_with::<Test>(&value, &Context { min: 4 }).unwrap_err()),
+        ["value: length is lower than 4 [length_lower_than]"]
+    );
+}
@@ -0,0 +1,80 @@
+use garde::Validate;
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(skip, label = "Password")]
+    password: &'a str,
+    #[garde(label("Password confirmation"), matches(password))]
+    password_confirmation: &'a str,
+    #[garde(skip)]
+    start: u32,
+    #[garde(label = "End date", greater_than(start))]
+    end: u32,
+    #[garde(inner(not_equal(password)))]
+    previous_passwords: &'a [&'a str],
+}
+
+#[test]
+fn label_valid() {
+    util::check_ok(
+        &[Test {
+            password: "hunter2",
+            password_confirmation: "hunter2",
+            start: 1,
+            end: 2,
+            previous_passwords: &["hunter1"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn label_invalid() {
+    util::check_fail!(
+        &[Test {
+            password: "hunter2",
+            password_confirmation: "hunter3",
+            start: 2,
+            end: 1,
+            previous_passwords: &["hunter2"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn label_params() {
+    let report = Test {
+        password: "hunter2",
+        password_confirmation: "hunter3",
+        start: 2,
+        end: 1,
+        previous_passwords: &[],
+    }
+    .validate()
+    .unwrap_err();
+
+    let (_, error) = report
+        .iter()
+        .find(|(path, _)| path.to_string() == "password_confirmation")
+        .unwrap();
+    assert_eq!(error.message(), "does not match Password field");
+    assert_eq!(
+        error.params().get("field").unwrap().as_str(),
+        Some("password")
+    );
+    assert_eq!(
+        error.params().get("label").unwrap().as_str(),
+        Some("Password")
+    );
+
+    // Fields without a label only have their name.
+    let (_, error) = report
+        .iter()
+        .find(|(path, _)| path.to_string() == "end")
+        .unwrap();
+    assert_eq!(error.message(), "not greater than start field");
+    assert!(error.params().get("label").is_none());
+}
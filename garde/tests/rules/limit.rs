@@ -0,0 +1,142 @@
+use std::cell::Cell;
+
+use garde::Validate;
+
+#[derive(Default)]
+struct Context {
+    calls: Cell<usize>,
+}
+
+fn counted(value: &str, ctx: &Context) -> garde::Result {
+    ctx.calls.set(ctx.calls.get() + 1);
+    if value.is_empty() {
+        return Err(garde::Error::new("empty"));
+    }
+    Ok(())
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context))]
+struct Item<'a> {
+    #[garde(custom(counted))]
+    a: &'a str,
+    #[garde(custom(counted))]
+    b: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context))]
+struct Test<'a> {
+    #[garde(dive)]
+    items: Vec<Item<'a>>,
+    #[garde(inner(custom(counted)))]
+    names: Vec<&'a str>,
+}
+
+fn invalid() -> Test<'static> {
+    Test {
+        items: (0..100).map(|_| Item { a: "", b: "" }).collect(),
+        names: vec![""; 100],
+    }
+}
+
+#[test]
+fn limit_unlimited() {
+    let ctx = Context::default();
+    let report = invalid().validate_with(&ctx).unwrap_err();
+    assert_eq!(report.iter().count(), 300);
+    assert_eq!(ctx.calls.get(), 300);
+}
+
+#[test]
+fn limit_zero_is_unlimited() {
+    let ctx = Context::default();
+    let report = invalid().validate_with_limit(&ctx, 0).unwrap_err();
+    assert_eq!(report.iter().count(), 300);
+    assert_eq!(ctx.calls.get(), 300);
+}
+
+#[test]
+fn limit_fail_fast() {
+    let ctx = Context::default();
+    let report = invalid().validate_with_limit(&ctx, 1).unwrap_err();
+    let errors = report
+        .iter()
+        .map(|(path, _)| path.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(errors, ["items[0].a"]);
+    assert_eq!(ctx.calls.get(), 1);
+}
+
+#[test]
+fn limit_n() {
+    let ctx = Context::default();
+    let report = invalid().validate_with_limit(&ctx, 5).unwrap_err();
+    let errors = report
+        .iter()
+        .map(|(path, _)| path.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        [
+            "items[0].a",
+            "items[0].b",
+            "items[1].a",
+            "items[1].b",
+            "items[2].a"
+        ]
+    );
+    assert_eq!(ctx.calls.get(), 5);
+}
+
+#[test]
+fn limit_inner() {
+    let ctx = Context::default();
+    let test = Test {
+        items: vec![],
+        ..invalid()
+    };
+    let report = test.validate_with_limit(&ctx, 2).unwrap_err();
+    let errors = report
+        .iter()
+        .map(|(path, _)| path.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(errors, ["names[0]", "names[1]"]);
+    assert_eq!(ctx.calls.get(), 2);
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context))]
+struct InnerFirst<'a> {
+    #[garde(inner(custom(counted)))]
+    names: Vec<&'a str>,
+    #[garde(custom(counted))]
+    other: &'a str,
+}
+
+#[test]
+fn limit_inner_stops_later_fields() {
+    let ctx = Context::default();
+    let test = InnerFirst {
+        names: vec![""; 2],
+        other: "",
+    };
+    let report = test.validate_with_limit(&ctx, 2).unwrap_err();
+    let errors = report
+        .iter()
+        .map(|(path, _)| path.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(errors, ["names[0]", "names[1]"]);
+    assert_eq!(ctx.calls.get(), 2);
+}
+
+#[test]
+fn limit_valid() {
+    let ctx = Context::default();
+    let test = Test {
+        items: vec![Item { a: "a", b: "b" }],
+        names: vec!["c"],
+    };
+    assert!(test.validate_with_limit(&ctx, 1).is_ok());
+    assert_eq!(ctx.calls.get(), 3);
+}
@@ -0,0 +1,91 @@
+use garde::error::Params;
+
+use super::util;
+
+struct Ctx {
+    unit: &'static str,
+}
+
+fn too_large(params: &Params, ctx: &Ctx) -> String {
+    format!(
+        "must be at most {} {}",
+        params.get("max").unwrap(),
+        ctx.unit
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Ctx))]
+struct Test<'a> {
+    #[garde(
+        length(min = 3, message = "too short"),
+        ascii(message = "only ASCII, please")
+    )]
+    name: &'a str,
+    #[garde(range(max = 10, message = too_large))]
+    amount: u32,
+    #[garde(contains("@", message = "must contain an at sign"))]
+    handle: &'a str,
+    #[garde(pattern(r"^\d+$", message = |_: &Params, _: &Ctx| "digits only"))]
+    digits: &'a str,
+    #[garde(inner(length(min = 1, message = "empty tag")))]
+    tags: &'a [&'a str],
+    #[garde(code = "custom_code", required(message = "please provide a value"))]
+    optional: Option<&'a str>,
+}
+
+#[test]
+fn message_valid() {
+    util::check_ok(
+        &[Test {
+            name: "test",
+            amount: 10,
+            handle: "@test",
+            digits: "123",
+            tags: &["a"],
+            optional: Some("a"),
+        }],
+        &Ctx { unit: "kg" },
+    )
+}
+
+#[test]
+fn message_invalid() {
+    util::check_fail!(
+        &[Test {
+            name: "ß",
+            amount: 11,
+            handle: "test",
+            digits: "abc",
+            tags: &[""],
+            optional: None,
+        }],
+        &Ctx { unit: "kg" },
+    )
+}
+
+#[test]
+fn message_keeps_code_and_params() {
+    use garde::Validate as _;
+
+    let report = Test {
+        name: "test",
+        amount: 11,
+        handle: "@test",
+        digits: "123",
+        tags: &[],
+        optional: None,
+    }
+    .validate_with(&Ctx { unit: "kg" })
+    .unwrap_err();
+
+    let errors = report.iter().map(|(_, error)| error).collect::<Vec<_>>();
+    assert_eq!(errors[0].message(), "must be at most 10 kg");
+    assert_eq!(errors[0].code(), Some("range_greater_than"));
+    assert_eq!(
+        errors[0].params().get("max"),
+        Some(&garde::error::Value::Int(10))
+    );
+    assert_eq!(errors[1].message(), "please provide a value");
+    assert_eq!(errors[1].code(), Some("custom_code"));
+}
@@ -2,6 +2,9 @@ mod adapt;
 mod allow_unvalidated;
 mod alphanumeric;
 mod ascii;
+mod async_validate;
+mod code;
+mod compare;
 mod contains;
 mod credit_card;
 mod custom;
@@ -9,22 +12,55 @@ mod dive;
 mod dive_with_ctx;
 mod dive_with_rules;
 mod email;
+mod groups;
+#[cfg(feature = "serde")]
+mod http;
 mod i18n;
+#[cfg(feature = "fluent")]
+mod i18n_fluent;
+#[cfg(all(
+    feature = "i18n-cs",
+    feature = "i18n-de",
+    feature = "i18n-es",
+    feature = "i18n-fr",
+    feature = "i18n-ja",
+    feature = "i18n-pt"
+))]
+mod i18n_locales;
 mod if_conditional;
 mod inner;
+mod introspect;
 mod ip;
+mod is_valid;
+#[cfg(feature = "json")]
+mod json;
+mod label;
 mod length;
+mod limit;
 mod matches;
+mod message;
 mod multi_rule;
 mod newtype;
 mod option;
+mod params;
+mod partial;
 mod pattern;
 mod phone_number;
 mod prefix;
 mod range;
+mod rename;
+mod report;
+mod required_conditional;
+mod sanitize;
+#[cfg(feature = "json-schema")]
+mod schema;
 mod select;
 mod skip;
 mod suffix;
 mod url;
+#[cfg(feature = "serde")]
+mod valid;
+#[cfg(feature = "zod")]
+mod zod;
 
 mod util;
@@ -0,0 +1,89 @@
+use garde::error::Value;
+use garde::Validate;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(length(min = 3, max = 10))]
+    short: &'a str,
+    #[garde(length(max = 3))]
+    long: &'a str,
+    #[garde(range(min = 10, max = 20))]
+    int: i32,
+    #[garde(range(max = 1.5))]
+    float: f64,
+    #[garde(pattern(r"^\d+$"))]
+    pattern: &'a str,
+    #[garde(prefix("pre_"), suffix("_suf"), contains("mid"))]
+    affixes: &'a str,
+    #[garde(skip)]
+    other: &'a str,
+    #[garde(matches(other))]
+    matches: &'a str,
+    #[garde(ipv4)]
+    ip: &'a str,
+    #[garde(email)]
+    email: &'a str,
+    #[garde(url)]
+    url: &'a str,
+    #[garde(ascii)]
+    ascii: &'a str,
+}
+
+#[test]
+fn params_invalid() {
+    let report = Test {
+        short: "a",
+        long: "abcd",
+        int: 5,
+        float: 2.0,
+        pattern: "abc",
+        affixes: "",
+        other: "a",
+        matches: "b",
+        ip: "::1",
+        email: "test",
+        url: "",
+        ascii: "ß",
+    }
+    .validate()
+    .unwrap_err();
+
+    let params = report
+        .iter()
+        .map(|(path, error)| {
+            let params = error
+                .params()
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.clone()))
+                .collect::<Vec<_>>();
+            (path.to_string(), error.code().unwrap().to_string(), params)
+        })
+        .collect::<Vec<_>>();
+
+    insta::assert_debug_snapshot!(params);
+}
+
+#[test]
+fn params_are_typed() {
+    let report = Test {
+        short: "a",
+        long: "",
+        int: 15,
+        float: 0.0,
+        pattern: "1",
+        affixes: "pre_mid_suf",
+        other: "a",
+        matches: "a",
+        ip: "127.0.0.1",
+        email: "test@example.com",
+        url: "https://example.com",
+        ascii: "a",
+    }
+    .validate()
+    .unwrap_err();
+
+    let (_, error) = report.iter().next().unwrap();
+    assert_eq!(error.params().get("min"), Some(&Value::Int(3)));
+    assert_eq!(error.params().get("min").and_then(Value::as_int), Some(3));
+    assert_eq!(error.params().get("max"), None);
+}
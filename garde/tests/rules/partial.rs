@@ -0,0 +1,107 @@
+use garde::Validate;
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(required, length(min = 3))]
+    name: Option<&'a str>,
+    #[garde(required_with(name), ascii)]
+    nickname: Option<&'a str>,
+    #[garde(required_without(nickname))]
+    email: Option<&'a str>,
+    #[garde(length(max = 3))]
+    always: &'a str,
+    #[garde(dive)]
+    inner: Option<Inner<'a>>,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Inner<'a> {
+    #[garde(required)]
+    value: Option<&'a str>,
+}
+
+#[test]
+fn partial_valid() {
+    garde::with_partial(|| {
+        util::check_ok(
+            &[
+                Test {
+                    name: None,
+                    nickname: None,
+                    email: None,
+                    always: "",
+                    inner: None,
+                },
+                Test {
+                    name: Some("abc"),
+                    nickname: None,
+                    email: None,
+                    always: "abc",
+                    inner: Some(Inner { value: None }),
+                },
+            ],
+            &(),
+        )
+    })
+}
+
+#[test]
+fn partial_invalid() {
+    garde::with_partial(|| {
+        util::check_fail!(
+            &[Test {
+                name: Some("ab"),
+                nickname: Some("ß"),
+                email: None,
+                always: "abcd",
+                inner: Some(Inner { value: None }),
+            }],
+            &(),
+        )
+    })
+}
+
+#[test]
+fn partial_validate_partial() {
+    let test = Test {
+        name: None,
+        nickname: None,
+        email: None,
+        always: "",
+        inner: Some(Inner { value: None }),
+    };
+    assert!(test.validate_partial().is_ok());
+    // Partial validation is only enabled for the duration of the call.
+    assert_eq!(test.validate().unwrap_err().iter().count(), 3);
+}
+
+fn always_fails(_: &Option<&str>, _: &()) -> garde::Result {
+    Err(garde::Error::new("custom rule was called"))
+}
+
+#[derive(Debug, garde::Validate)]
+struct Absent<'a> {
+    #[garde(skip)]
+    password: Option<&'a str>,
+    #[garde(custom(always_fails))]
+    custom: Option<&'a str>,
+    #[garde(matches(password))]
+    password_confirmation: Option<&'a str>,
+}
+
+#[test]
+fn partial_skips_absent_fields() {
+    let test = Absent {
+        password: Some("secret"),
+        custom: None,
+        password_confirmation: None,
+    };
+    assert!(test.validate_partial().is_ok());
+    assert!(garde::with_partial(|| test.is_valid()));
+    assert_eq!(
+        test.validate().unwrap_err().to_string(),
+        "custom: custom rule was called\npassword_confirmation: does not match password field\n"
+    );
+}
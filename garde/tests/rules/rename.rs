@@ -0,0 +1,52 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Inner<'a> {
+    #[garde(rename = "innerField", length(min = 1))]
+    field: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(rename("userName"), ascii, length(min = 3))]
+    user_name: &'a str,
+    #[garde(rename = "nestedValue", dive)]
+    nested_value: Inner<'a>,
+    #[garde(rename = "tagList", inner(length(min = 1)))]
+    tag_list: &'a [&'a str],
+}
+
+#[test]
+fn rename_valid() {
+    util::check_ok(
+        &[Test {
+            user_name: "test",
+            nested_value: Inner { field: "test" },
+            tag_list: &["test"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn rename_invalid() {
+    util::check_fail!(
+        &[Test {
+            user_name: "ß",
+            nested_value: Inner { field: "" },
+            tag_list: &[""],
+        }],
+        &(),
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Tuple<'a>(
+    #[garde(rename = "first", length(min = 1))] &'a str,
+    #[garde(length(min = 1))] &'a str,
+);
+
+#[test]
+fn rename_tuple_invalid() {
+    util::check_fail!(&[Tuple("", "")], &())
+}
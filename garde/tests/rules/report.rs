@@ -0,0 +1,94 @@
+use garde::error::Reporter;
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+#[garde(report(at_least_one_contact))]
+struct Contact<'a> {
+    #[garde(skip)]
+    email: Option<&'a str>,
+    #[garde(skip)]
+    phone: Option<&'a str>,
+}
+
+fn at_least_one_contact(value: &Contact, _: &(), report: &mut Reporter) {
+    if value.email.is_none() && value.phone.is_none() {
+        let error = garde::Error::new("either `email` or `phone` must be set");
+        report.at("email").append(error.clone());
+        report.at("phone").append(error);
+    }
+}
+
+struct Context {
+    max_days: u32,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context as ctx))]
+#[garde(report(|value: &Range, ctx: &Context, report: &mut Reporter| {
+    if value.end < value.start {
+        report.at("end").append(garde::Error::new("ends before it starts"));
+    } else if value.end - value.start > ctx.max_days {
+        report.append(garde::Error::new(format!("longer than {} days", ctx.max_days)));
+    }
+}))]
+struct Range {
+    #[garde(skip)]
+    start: u32,
+    #[garde(skip)]
+    end: u32,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(report(|value: &Renamed, _: &(), report: &mut Reporter| {
+    if value.email_address.is_empty() {
+        report.at("email_address").append(garde::Error::new("not set"));
+    }
+}))]
+struct Renamed<'a> {
+    #[garde(skip, rename = "emailAddress")]
+    email_address: &'a str,
+}
+
+#[test]
+fn report_at_uses_rename() {
+    let report = garde::Validate::validate(&Renamed { email_address: "" }).unwrap_err();
+    assert_eq!(report.to_string(), "emailAddress: not set\n");
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context))]
+struct Test<'a> {
+    #[garde(dive(&()))]
+    contact: Contact<'a>,
+    #[garde(dive)]
+    ranges: Vec<Range>,
+}
+
+#[test]
+fn report_valid() {
+    util::check_ok(
+        &[Test {
+            contact: Contact {
+                email: Some("a@b.c"),
+                phone: None,
+            },
+            ranges: vec![Range { start: 1, end: 5 }],
+        }],
+        &Context { max_days: 7 },
+    )
+}
+
+#[test]
+fn report_invalid() {
+    util::check_fail!(
+        &[Test {
+            contact: Contact {
+                email: None,
+                phone: None,
+            },
+            ranges: vec![Range { start: 5, end: 1 }, Range { start: 1, end: 10 }],
+        }],
+        &Context { max_days: 7 },
+    )
+}
@@ -0,0 +1,90 @@
+use super::util;
+
+#[derive(Debug, PartialEq)]
+enum Kind {
+    Person,
+    Company,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(skip)]
+    kind: Kind,
+    #[garde(required_if(self.kind == Kind::Company))]
+    company_name: Option<&'a str>,
+
+    #[garde(skip)]
+    vat_id: Option<&'a str>,
+    #[garde(required_with(vat_id))]
+    vat_country: Option<&'a str>,
+
+    #[garde(required_without(email))]
+    phone: Option<&'a str>,
+    #[garde(required_without(phone))]
+    email: Option<&'a str>,
+}
+
+#[test]
+fn required_conditional_valid() {
+    util::check_ok(
+        &[
+            Test {
+                kind: Kind::Person,
+                company_name: None,
+                vat_id: None,
+                vat_country: None,
+                phone: Some("+1"),
+                email: None,
+            },
+            Test {
+                kind: Kind::Company,
+                company_name: Some("ACME"),
+                vat_id: Some("123"),
+                vat_country: Some("CZ"),
+                phone: None,
+                email: Some("a@b.c"),
+            },
+            Test {
+                kind: Kind::Person,
+                company_name: None,
+                vat_id: None,
+                vat_country: Some("CZ"),
+                phone: Some("+1"),
+                email: Some("a@b.c"),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn required_conditional_invalid() {
+    util::check_fail!(
+        &[Test {
+            kind: Kind::Company,
+            company_name: None,
+            vat_id: Some("123"),
+            vat_country: None,
+            phone: None,
+            email: None,
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn required_if_has_its_own_code() {
+    let report = garde::Validate::validate(&Test {
+        kind: Kind::Company,
+        company_name: None,
+        vat_id: None,
+        vat_country: None,
+        phone: Some("+1"),
+        email: None,
+    })
+    .unwrap_err();
+    let (path, error) = report.iter().next().unwrap();
+    assert_eq!(path.to_string(), "company_name");
+    assert_eq!(error.message(), "not set");
+    assert_eq!(error.code(), Some("required_if_not_set"));
+}
@@ -0,0 +1,108 @@
+use garde::{Sanitize, Unvalidated};
+
+fn strip_tag(value: &mut String) {
+    if let Some((local, domain)) = value.split_once('@') {
+        if let Some((local, _)) = local.split_once('+') {
+            *value = format!("{local}@{domain}");
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, garde::Validate, garde::Sanitize)]
+struct User {
+    #[garde(trim, lowercase, sanitize(strip_tag), email)]
+    email: String,
+    #[garde(trim, collapse_whitespace, length(min = 1))]
+    name: String,
+    #[garde(uppercase, length(equal = 2))]
+    country: Option<String>,
+    #[garde(dive)]
+    addresses: Vec<Address>,
+    #[garde(skip, trim)]
+    nickname: String,
+    #[garde(skip)]
+    untouched: String,
+}
+
+#[derive(Debug, PartialEq, garde::Validate, garde::Sanitize)]
+struct Address(
+    #[garde(trim, length(min = 1))] String,
+    #[garde(skip)] String,
+);
+
+#[derive(Debug, PartialEq, garde::Validate, garde::Sanitize)]
+enum Contact {
+    Email(#[garde(trim, lowercase, email)] String),
+    Phone {
+        #[garde(collapse_whitespace, ascii)]
+        number: String,
+    },
+    None,
+}
+
+fn user() -> User {
+    User {
+        email: "  Test+News@Example.com ".into(),
+        name: "\tJohn \n  Doe ".into(),
+        country: Some("cz".into()),
+        addresses: vec![Address(" street ".into(), " x ".into())],
+        nickname: " nick ".into(),
+        untouched: " keep ".into(),
+    }
+}
+
+#[test]
+fn sanitize_fields() {
+    let mut user = user();
+    user.sanitize();
+    assert_eq!(
+        user,
+        User {
+            email: "test@example.com".into(),
+            name: "John Doe".into(),
+            country: Some("CZ".into()),
+            addresses: vec![Address("street".into(), " x ".into())],
+            nickname: "nick".into(),
+            untouched: " keep ".into(),
+        }
+    );
+}
+
+#[test]
+fn sanitize_enum() {
+    let mut contact = Contact::Email(" A@B.com".into());
+    contact.sanitize();
+    assert_eq!(contact, Contact::Email("a@b.com".into()));
+
+    let mut contact = Contact::Phone {
+        number: "+420  123\t456".into(),
+    };
+    contact.sanitize();
+    assert_eq!(
+        contact,
+        Contact::Phone {
+            number: "+420 123 456".into()
+        }
+    );
+
+    let mut contact = Contact::None;
+    contact.sanitize();
+    assert_eq!(contact, Contact::None);
+}
+
+#[test]
+fn sanitize_and_validate_valid() {
+    let user = Unvalidated::new(user()).sanitize_and_validate().unwrap();
+    assert_eq!(user.email, "test@example.com");
+}
+
+#[test]
+fn sanitize_and_validate_invalid() {
+    let user = User {
+        name: " \t ".into(),
+        addresses: vec![Address("   ".into(), String::new())],
+        ..user()
+    };
+    let report = Unvalidated::new(user).sanitize_and_validate().unwrap_err();
+    insta::assert_snapshot!(report.to_string());
+}
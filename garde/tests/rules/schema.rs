@@ -0,0 +1,232 @@
+use garde::schema::GardeSchema;
+use serde_json::json;
+
+#[derive(garde::Validate)]
+struct User<'a> {
+    #[garde(ascii, length(chars, min = 3, max = 25))]
+    username: &'a str,
+    #[garde(length(max = 64))]
+    password: String,
+    #[garde(email)]
+    email: String,
+    #[garde(url)]
+    website: Option<String>,
+    #[garde(range(min = 18, max = 150))]
+    age: u8,
+    #[garde(range(min = -1.5))]
+    score: f64,
+    #[garde(length(min = 1), inner(length(chars, max = 16)))]
+    tags: Vec<String>,
+    #[garde(inner(range(min = 0)))]
+    limits: [i32; 2],
+    #[garde(required, length(chars, max = 140))]
+    bio: Option<String>,
+    #[garde(length(min = ctx_min(&self.username)))]
+    dynamic: String,
+    #[garde(dive)]
+    address: Address,
+    #[garde(skip)]
+    #[allow(dead_code)]
+    internal: u64,
+}
+
+fn ctx_min(_: &str) -> usize {
+    1
+}
+
+#[derive(garde::Validate)]
+struct Address {
+    #[garde(rename = "streetName", length(chars, min = 1))]
+    street: String,
+}
+
+#[test]
+fn struct_schema() {
+    assert_eq!(
+        User::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "username": { "type": "string", "minLength": 3, "maxLength": 25 },
+                "email": { "type": "string", "format": "email" },
+                "password": { "type": "string" },
+                "website": { "type": ["string", "null"], "format": "uri" },
+                "age": { "type": "integer", "minimum": 18, "maximum": 150 },
+                "score": { "type": "number", "minimum": -1.5 },
+                "tags": {
+                    "type": "array",
+                    "items": { "type": "string", "maxLength": 16 },
+                    "minItems": 1,
+                },
+                "limits": {
+                    "type": "array",
+                    "items": { "type": "integer", "minimum": 0 },
+                    "minItems": 2,
+                    "maxItems": 2,
+                },
+                "bio": { "type": "string", "maxLength": 140 },
+                "dynamic": { "type": "string" },
+                "address": {
+                    "type": "object",
+                    "properties": {
+                        "streetName": { "type": "string", "minLength": 1 },
+                    },
+                    "required": ["streetName"],
+                },
+            },
+            "required": ["address", "age", "bio", "dynamic", "email", "limits", "password", "score", "tags", "username"],
+        })
+    );
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn pattern_schema() {
+    #[derive(garde::Validate)]
+    struct Test {
+        #[garde(pattern(r"^[a-z]+$"))]
+        field: String,
+    }
+
+    assert_eq!(
+        Test::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "field": { "type": "string", "pattern": "^[a-z]+$" },
+            },
+            "required": ["field"],
+        })
+    );
+}
+
+#[derive(garde::Validate)]
+#[garde(transparent)]
+struct Username(#[garde(length(chars, min = 3))] String);
+
+#[derive(garde::Validate)]
+struct Point(#[garde(range(min = 0))] i32, #[garde(range(max = 0))] i32);
+
+#[test]
+fn tuple_schema() {
+    assert_eq!(
+        Username::json_schema(),
+        json!({ "type": "string", "minLength": 3 })
+    );
+    assert_eq!(
+        Point::json_schema(),
+        json!({
+            "type": "array",
+            "prefixItems": [
+                { "type": "integer", "minimum": 0 },
+                { "type": "integer", "maximum": 0 },
+            ],
+            "minItems": 2,
+            "maxItems": 2,
+        })
+    );
+}
+
+#[derive(garde::Validate)]
+#[allow(dead_code)]
+enum Shape {
+    Empty,
+    Circle(#[garde(range(min = 0.0))] f64),
+    Rect {
+        #[garde(range(min = 0))]
+        width: u32,
+        #[garde(range(min = 0))]
+        height: u32,
+    },
+}
+
+#[test]
+fn enum_schema() {
+    assert_eq!(
+        Shape::json_schema(),
+        json!({
+            "oneOf": [
+                { "const": "Empty" },
+                {
+                    "type": "object",
+                    "properties": {
+                        "Circle": { "type": "number", "minimum": 0.0 },
+                    },
+                    "required": ["Circle"],
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "Rect": {
+                            "type": "object",
+                            "properties": {
+                                "width": { "type": "integer", "minimum": 0 },
+                                "height": { "type": "integer", "minimum": 0 },
+                            },
+                            "required": ["height", "width"],
+                        },
+                    },
+                    "required": ["Rect"],
+                },
+            ],
+        })
+    );
+}
+
+struct Opaque;
+
+#[derive(garde::Validate)]
+struct Unknown {
+    #[garde(skip)]
+    #[allow(dead_code)]
+    opaque: Opaque,
+    #[garde(custom(|_: &Opaque, _: &()| Ok(())))]
+    other: Opaque,
+    #[garde(custom(|_: &Option<Opaque>, _: &()| Ok(())))]
+    maybe: Option<Opaque>,
+}
+
+#[test]
+fn unknown_type_schema() {
+    assert_eq!(
+        Unknown::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "other": {},
+                "maybe": {},
+            },
+            "required": ["other"],
+        })
+    );
+}
+
+#[derive(garde::Validate)]
+struct Nullable {
+    #[garde(length(min = 1))]
+    list: Option<Vec<u8>>,
+    #[garde(dive)]
+    shape: Option<Shape>,
+    #[garde(inner(required))]
+    items: Vec<Option<u8>>,
+}
+
+#[test]
+fn nullable_schema() {
+    assert_eq!(
+        Nullable::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "list": {
+                    "type": ["array", "null"],
+                    "items": { "type": "integer" },
+                    "minItems": 1,
+                },
+                "shape": { "anyOf": [Shape::json_schema(), { "type": "null" }] },
+                "items": { "type": "array", "items": { "type": "integer" } },
+            },
+            "required": ["items"],
+        })
+    );
+}
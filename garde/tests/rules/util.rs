@@ -19,6 +19,12 @@ pub fn check_ok<T: Validate + Debug>(cases: &[T], ctx: &T::Context) {
                     .join("; ")
             );
             some_failed = true;
+        } else if !case.is_valid_with(ctx) {
+            eprintln!(
+                "{} input: {case:?}, `is_valid` returned false",
+                "FAIL".red()
+            );
+            some_failed = true;
         }
     }
 
@@ -32,6 +38,13 @@ pub fn __check_fail<T: Validate + Debug>(cases: &[T], ctx: &T::Context) -> Strin
     let mut some_success = false;
     let mut snapshot = String::new();
     for case in cases {
+        if case.is_valid_with(ctx) {
+            eprintln!(
+                "{} input: {case:?}, `is_valid` returned true",
+                "SUCCESS".red()
+            );
+            some_success = true;
+        }
         if let Err(report) = case.validate_with(ctx) {
             writeln!(&mut snapshot, "{case:#?}").unwrap();
             write!(&mut snapshot, "{report}").unwrap();
@@ -0,0 +1,47 @@
+use garde::Valid;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, garde::Validate)]
+struct Test {
+    #[garde(length(min = 3))]
+    name: String,
+    #[garde(range(max = 10))]
+    count: u8,
+}
+
+#[test]
+fn valid_deserialize() {
+    let value: Valid<Test> = serde_json::from_str(r#"{ "name": "abc", "count": 1 }"#).unwrap();
+    assert_eq!(value.name, "abc");
+    assert_eq!(value.count, 1);
+}
+
+#[test]
+fn valid_deserialize_invalid() {
+    let error = serde_json::from_str::<Valid<Test>>(r#"{ "name": "ab", "count": 11 }"#)
+        .unwrap_err()
+        .to_string();
+    assert_eq!(
+        error,
+        "count: greater than 10\nname: length is lower than 3"
+    );
+}
+
+#[test]
+fn valid_deserialize_type_error() {
+    let error = serde_json::from_str::<Valid<Test>>(r#"{ "name": 1, "count": 1 }"#)
+        .unwrap_err()
+        .to_string();
+    assert_eq!(
+        error,
+        "invalid type: integer `1`, expected a string at line 1 column 11"
+    );
+}
+
+#[test]
+fn valid_serialize() {
+    let value: Valid<Test> = serde_json::from_str(r#"{ "name": "abc", "count": 1 }"#).unwrap();
+    assert_eq!(
+        serde_json::to_string(&value).unwrap(),
+        r#"{"name":"abc","count":1}"#
+    );
+}
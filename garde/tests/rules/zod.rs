@@ -0,0 +1,126 @@
+use garde::schema::GardeSchema;
+use garde::zod::{to_zod, Generator};
+
+#[derive(garde::Validate)]
+struct User {
+    #[garde(ascii, length(chars, min = 3, max = 25))]
+    username: String,
+    #[garde(email)]
+    email: String,
+    #[garde(url)]
+    website: Option<String>,
+    #[garde(range(min = 18))]
+    age: u8,
+    #[garde(length(max = 5), inner(length(min = 1)))]
+    tags: Vec<String>,
+    #[garde(required, ipv4)]
+    ip: Option<String>,
+    #[garde(dive)]
+    address: Address,
+}
+
+#[derive(garde::Validate)]
+struct Address {
+    #[garde(rename = "street-name", length(chars, min = 1))]
+    street: String,
+    #[garde(skip)]
+    #[allow(dead_code)]
+    internal: u64,
+}
+
+#[derive(garde::Validate)]
+#[allow(dead_code)]
+enum Shape {
+    Empty,
+    Circle(#[garde(range(min = 0.0))] f64),
+    Point(#[garde(skip)] i32, #[garde(skip)] i32),
+}
+
+#[test]
+fn struct_to_zod() {
+    assert_eq!(
+        to_zod(&User::json_schema()),
+        concat!(
+            "z.object({ ",
+            r#"address: z.object({ "street-name": z.string().refine((v) => [...v].length >= 1) }), "#,
+            "age: z.number().int().min(18), ",
+            "email: z.string().email(), ",
+            r#"ip: z.string().ip({ version: "v4" }), "#,
+            "tags: z.array(z.string()).max(5), ",
+            "username: z.string().refine((v) => [...v].length >= 3).refine((v) => [...v].length <= 25), ",
+            "website: z.string().url().nullish()",
+            " })",
+        )
+    );
+}
+
+#[test]
+fn enum_to_zod() {
+    assert_eq!(
+        to_zod(&Shape::json_schema()),
+        concat!(
+            "z.union([",
+            r#"z.literal("Empty"), "#,
+            "z.object({ Circle: z.number().min(0.0) }), ",
+            "z.object({ Point: z.tuple([z.number().int(), z.number().int()]) })",
+            "])",
+        )
+    );
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn pattern_to_zod() {
+    #[derive(garde::Validate)]
+    struct Test {
+        #[garde(pattern(r"^\d+$"))]
+        field: String,
+    }
+
+    assert_eq!(
+        to_zod(&Test::json_schema()),
+        r#"z.object({ field: z.string().regex(new RegExp("^\\d+$")) })"#
+    );
+}
+
+#[test]
+fn generate_module() {
+    let output = Generator::new()
+        .add::<Address>("Address")
+        .add::<Vec<u8>>("Bytes")
+        .generate();
+    assert_eq!(
+        output,
+        r#"// This file is generated by garde. Do not edit it by hand.
+
+import { z } from "zod";
+
+export const Address = z.object({ "street-name": z.string().refine((v) => [...v].length >= 1) });
+export type Address = z.infer<typeof Address>;
+
+export const Bytes = z.array(z.number().int());
+export type Bytes = z.infer<typeof Bytes>;
+"#
+    );
+}
+
+#[test]
+fn nullable_to_zod() {
+    #[derive(garde::Validate)]
+    struct Test {
+        #[garde(inner(range(min = 1)))]
+        scores: Vec<Option<u8>>,
+        #[garde(length(chars, max = 3))]
+        code: Option<String>,
+    }
+
+    assert_eq!(
+        to_zod(&Test::json_schema()),
+        concat!(
+            "z.object({ ",
+            "code: z.string().refine((v) => [...v].length <= 3).nullish(), ",
+            "scores: z.array(z.number().int().min(1).nullable())",
+            " })",
+        )
+    );
+}
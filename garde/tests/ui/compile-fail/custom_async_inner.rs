@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+async fn check(_: &str, _: &()) -> garde::Result {
+    Ok(())
+}
+
+#[derive(garde::AsyncValidate)]
+struct Test<'a> {
+    #[garde(inner(custom_async(check)))]
+    field: &'a [&'a str],
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+async fn check(_: &str, _: &()) -> garde::Result {
+    Ok(())
+}
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(custom_async(check))]
+    field: &'a str,
+}
+
+fn main() {}
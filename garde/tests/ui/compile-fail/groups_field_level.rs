@@ -0,0 +1,19 @@
+#![allow(dead_code)]
+
+struct Create;
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(dive, rename = "inner", groups(Create))]
+    inner: Inner<'a>,
+    #[garde(length(min = 1), code = "too_short", groups(Create))]
+    field: &'a str,
+}
+
+#[derive(garde::Validate)]
+struct Inner<'a> {
+    #[garde(ascii)]
+    field: &'a str,
+}
+
+fn main() {}
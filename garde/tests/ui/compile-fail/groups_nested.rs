@@ -0,0 +1,9 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(inner(length(min = 1), groups(Create)))]
+    list: &'a [&'a str],
+}
+
+fn main() {}
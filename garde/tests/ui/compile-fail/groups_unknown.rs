@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+struct Create;
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(length(min = 1), groups(Craete))]
+    field: &'a str,
+}
+
+fn main() {}
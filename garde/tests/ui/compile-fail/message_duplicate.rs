@@ -0,0 +1,9 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(length(min = 1, message = "a", message = "b"))]
+    field: &'a str,
+}
+
+fn main() {}
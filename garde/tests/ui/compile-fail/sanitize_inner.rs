@@ -0,0 +1,9 @@
+#![allow(dead_code)]
+
+#[derive(garde::Sanitize)]
+struct Test {
+    #[garde(inner(trim))]
+    field: Vec<String>,
+}
+
+fn main() {}
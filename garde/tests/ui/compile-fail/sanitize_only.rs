@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate, garde::Sanitize)]
+struct Test {
+    #[garde(trim)]
+    only_sanitized: String,
+    #[garde(skip, trim)]
+    skipped: String,
+    #[garde(trim, ascii)]
+    validated: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(code = "field_invalid", ascii)]
+    field: &'a str,
+    #[garde(code("inner_invalid"), inner(ascii))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
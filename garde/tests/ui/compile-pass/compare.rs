@@ -0,0 +1,17 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(skip)]
+    min: u64,
+    #[garde(greater_than(min), less_than_or_equal(max))]
+    value: u64,
+    #[garde(greater_than_or_equal(min), less_than(value))]
+    max: u64,
+    #[garde(skip)]
+    foo: &'a str,
+    #[garde(not_equal(foo))]
+    bar: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+#![allow(dead_code)]
+
+struct Create;
+struct Update;
+
+mod groups {
+    pub struct Admin;
+}
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(ascii)]
+    #[garde(length(min = 1), alphanumeric, groups(Create, Update))]
+    field: &'a str,
+    #[garde(inner(length(min = 1)), groups(Create))]
+    list: &'a [&'a str],
+    #[garde(rename = "other", code = "other_invalid")]
+    #[garde(required, groups(groups::Admin))]
+    other: Option<&'a str>,
+}
+
+fn main() {}
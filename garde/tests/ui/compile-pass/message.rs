@@ -0,0 +1,15 @@
+#![allow(dead_code)]
+
+fn message(_: &garde::error::Params, _: &()) -> &'static str {
+    "custom message"
+}
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(ascii(message = "not ascii"), length(message = message, min = 1))]
+    field: &'a str,
+    #[garde(inner(prefix("a", message = "missing prefix")))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(rename = "fieldName", ascii)]
+    field: &'a str,
+    #[garde(rename("innerName"), inner(ascii))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+#[garde(report(check))]
+struct Test<'a> {
+    #[garde(ascii)]
+    field: &'a str,
+}
+
+fn check(value: &Test, _: &(), report: &mut garde::error::Reporter) {
+    if value.field.is_empty() {
+        report.at("field").append(garde::Error::new("empty"));
+    }
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+#![allow(dead_code)]
+
+struct Context {
+    strict: bool,
+}
+
+#[derive(garde::Validate)]
+#[garde(context(Context as ctx))]
+struct Test<'a> {
+    #[garde(required_if(ctx.strict))]
+    a: Option<&'a str>,
+    #[garde(required_with(a))]
+    b: Option<&'a str>,
+    #[garde(required_without(b), length(min = 1))]
+    c: Option<&'a str>,
+}
+
+fn main() {}
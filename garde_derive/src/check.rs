@@ -1,4 +1,4 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 use proc_macro2::Span;
 use syn::spanned::Spanned;
@@ -8,7 +8,7 @@ use crate::model;
 use crate::model::LengthMode;
 use crate::util::{default_ctx_name, MaybeFoldError};
 
-pub fn check(input: model::Input) -> syn::Result<model::Validate> {
+pub fn check(input: model::Input, derive: model::Derive) -> syn::Result<model::Validate> {
     let model::Input {
         ident,
         generics,
@@ -34,7 +34,10 @@ pub fn check(input: model::Input) -> syn::Result<model::Validate> {
 
     let custom_rules = get_custom_attrs(&attrs);
 
-    let options = get_options(&attrs);
+    let report_rules = get_report_attrs(&attrs);
+
+    let mut options = get_options(&attrs);
+    options.derive = derive;
 
     let kind = match kind {
         model::InputKind::Struct(variant) => {
@@ -86,6 +89,7 @@ pub fn check(input: model::Input) -> syn::Result<model::Validate> {
         is_transparent: transparent.is_some(),
         kind,
         custom_rules,
+        report_rules,
         options,
     })
 }
@@ -95,7 +99,7 @@ fn check_attrs(attrs: &[(Span, model::Attr)]) -> syn::Result<()> {
 
     let mut set = BTreeSet::new();
     for (span, attr) in attrs {
-        if matches!(attr, model::Attr::Custom(..)) {
+        if matches!(attr, model::Attr::Custom(..) | model::Attr::Report(..)) {
             continue;
         }
         let d = attr.discriminant();
@@ -157,6 +161,16 @@ fn get_custom_attrs(attrs: &[(Span, model::Attr)]) -> Vec<Expr> {
         .collect()
 }
 
+fn get_report_attrs(attrs: &[(Span, model::Attr)]) -> Vec<Expr> {
+    attrs
+        .iter()
+        .filter_map(|(_, attr)| match attr {
+            model::Attr::Report(expr) => Some(expr.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
 fn is_unary_struct(k: &model::ValidateKind) -> bool {
     match k {
         model::ValidateKind::Struct(model::ValidateVariant::Tuple(fields)) => {
@@ -176,6 +190,7 @@ fn is_unary_struct(k: &model::ValidateKind) -> bool {
 fn get_options(attrs: &[(Span, model::Attr)]) -> model::Options {
     let mut options = model::Options {
         allow_unvalidated: false,
+        derive: model::Derive::Validate,
     };
 
     for (_, attr) in attrs {
@@ -208,6 +223,7 @@ fn check_variant(
                 };
                 fields.push((ident, field))
             }
+            resolve_labels(&mut fields);
             model::ValidateVariant::Struct(fields)
         }
         model::Variant::Tuple(list) => {
@@ -233,6 +249,55 @@ fn check_variant(
     Ok(variant)
 }
 
+/// Copies the `label` of each field onto the rules which refer to it, e.g. `matches(password)`.
+///
+/// This can only be done once every field of the struct has been checked.
+fn resolve_labels(fields: &mut [(syn::Ident, model::ValidateField)]) {
+    let labels: BTreeMap<String, String> = fields
+        .iter()
+        .filter_map(|(ident, field)| Some((ident.to_string(), field.label.clone()?)))
+        .collect();
+    if labels.is_empty() {
+        return;
+    }
+    for (_, field) in fields.iter_mut() {
+        resolve_rule_set_labels(&mut field.rule_set, &labels);
+    }
+}
+
+fn resolve_rule_set_labels(rule_set: &mut model::RuleSet, labels: &BTreeMap<String, String>) {
+    use model::ValidateRule::*;
+
+    // Rules are only compared by their kind, so updating them doesn't change their order.
+    rule_set.rules = std::mem::take(&mut rule_set.rules)
+        .into_iter()
+        .map(|(mut rule, message)| {
+            if let RequiredWith(field)
+            | RequiredWithout(field)
+            | Matches(field)
+            | NotEqual(field)
+            | GreaterThan(field)
+            | GreaterThanOrEqual(field)
+            | LessThan(field)
+            | LessThanOrEqual(field) = &mut rule
+            {
+                field.label = field
+                    .path
+                    .get_ident()
+                    .and_then(|ident| labels.get(&ident.to_string()))
+                    .cloned();
+            }
+            (rule, message)
+        })
+        .collect();
+    if let Some(inner) = &mut rule_set.inner {
+        resolve_rule_set_labels(inner, labels);
+    }
+    for conditional in &mut rule_set.conditional_rule_sets {
+        resolve_rule_set_labels(&mut conditional.rule_set, labels);
+    }
+}
+
 fn check_field(field: model::Field, options: &model::Options) -> syn::Result<model::ValidateField> {
     let mut error = None;
 
@@ -246,15 +311,35 @@ fn check_field(field: model::Field, options: &model::Options) -> syn::Result<mod
         adapter: None,
         skip: None,
         alias: None,
-        // message: None,
+        label: None,
         code: None,
         dive: None,
         rule_set: model::RuleSet::empty(),
+        sanitizers: Vec::new(),
+    };
+
+    field.rule_set = match check_rules(&mut field, raw_rules) {
+        Ok(v) => v,
+        Err(e) => {
+            error.maybe_fold(e);
+            model::RuleSet::empty()
+        }
     };
 
-    if raw_rules.is_empty() {
+    // Field-level attributes such as `rename` don't validate anything on their own,
+    // and neither do sanitizers, which only `Sanitize` applies.
+    let is_empty = match options.derive {
+        model::Derive::Sanitize => field.is_empty(),
+        model::Derive::Validate | model::Derive::AsyncValidate => !field.has_validation(),
+    };
+    if error.is_none() && field.skip.is_none() && is_empty {
         if options.allow_unvalidated {
             field.skip = Some(Span::call_site());
+        } else if !field.sanitizers.is_empty() {
+            error.maybe_fold(syn::Error::new(
+                field.ty.span(),
+                "field is only sanitized and has no validation, use `#[garde(skip)]` alongside the sanitizers if this is intentional",
+            ));
         } else {
             error.maybe_fold(syn::Error::new(
                 field.ty.span(),
@@ -263,16 +348,8 @@ fn check_field(field: model::Field, options: &model::Options) -> syn::Result<mod
         }
     }
 
-    field.rule_set = match check_rules(&mut field, raw_rules) {
-        Ok(v) => v,
-        Err(e) => {
-            error.maybe_fold(e);
-            model::RuleSet::empty()
-        }
-    };
-
     if let Some(span) = field.skip {
-        if !field.is_empty() {
+        if field.has_validation() {
             error.maybe_fold(syn::Error::new(
                 span,
                 "`skip` may not be combined with other rules",
@@ -280,6 +357,15 @@ fn check_field(field: model::Field, options: &model::Options) -> syn::Result<mod
         }
     }
 
+    if options.derive == model::Derive::Validate {
+        if let Some(rule) = field.rule_set.first_async_rule() {
+            error.maybe_fold(syn::Error::new(
+                rule.span(),
+                "rule `custom_async` requires `#[derive(AsyncValidate)]`",
+            ))
+        }
+    }
+
     if let Some((span, _)) = field.dive {
         if field.rule_set.has_inner_rules() {
             error.maybe_fold(syn::Error::new(
@@ -320,6 +406,12 @@ fn check_rule(
     is_inner: bool,
     is_conditional: bool,
 ) -> syn::Result<()> {
+    let model::RawRule {
+        span,
+        kind,
+        message,
+    } = raw_rule;
+
     macro_rules! apply {
         ($name:ident = $value:expr, $span:expr) => {{
             if is_inner || is_conditional {
@@ -341,24 +433,53 @@ fn check_rule(
 
         ($rule:ident($($inner:expr)?), $span:expr) => {{
             let rule = model::ValidateRule::$rule$(($inner))?;
-            let name = rule.name();
-            if !rule_set.rules.insert(rule) {
+            if rule_set.rules.contains_key(&rule) {
+                let name = rule.name();
                 return Err(syn::Error::new($span, format!("duplicate rule `{name}`")));
             }
+            rule_set.rules.insert(rule, message);
+        }};
+    }
+
+    macro_rules! sanitize {
+        ($sanitizer:ident $(($inner:expr))?, $name:literal) => {{
+            if is_inner || is_conditional {
+                return Err(syn::Error::new(
+                    span,
+                    concat!("rule `", $name, "` may only be used at the field level"),
+                ));
+            }
+            field.sanitizers.push(model::Sanitizer::$sanitizer$(($inner))?);
         }};
     }
 
-    let span = raw_rule.span;
     use model::RawRuleKind::*;
-    match raw_rule.kind {
+    match kind {
         Skip => apply!(skip = span, span),
         Adapt(path) => apply!(adapter = path, span),
         Rename(alias) => apply!(alias = alias.value, span),
-        // Message(message) => apply!(message = message, span),
+        Label(label) => apply!(label = label.value, span),
         Code(code) => apply!(code = code.value, span),
         Dive(ctx) => apply!(dive = (span, ctx), span),
         Custom(custom) => rule_set.custom_rules.push(custom),
+        Trim => sanitize!(Trim, "trim"),
+        Lowercase => sanitize!(Lowercase, "lowercase"),
+        Uppercase => sanitize!(Uppercase, "uppercase"),
+        CollapseWhitespace => sanitize!(CollapseWhitespace, "collapse_whitespace"),
+        Sanitize(custom) => sanitize!(Custom(custom), "sanitize"),
+        CustomAsync(custom) => {
+            if is_inner {
+                return Err(syn::Error::new(
+                    span,
+                    "rule `custom_async` may not be used inside `inner`",
+                ));
+            }
+            rule_set.custom_async_rules.push(custom)
+        }
         Required => apply!(Required(), span),
+        RequiredIf(cond) => apply!(RequiredIf(cond), span),
+        RequiredWith(path) => apply!(RequiredWith(model::FieldRef::new(path)), span),
+        RequiredWithout(path) => apply!(RequiredWithout(model::FieldRef::new(path)), span),
         Ascii => apply!(Ascii(), span),
         Alphanumeric => apply!(Alphanumeric(), span),
         Email => apply!(Email(), span),
@@ -378,7 +499,12 @@ fn check_rule(
                 LengthMode::Utf16 => apply!(LengthUtf16(range), span),
             }
         }
-        Matches(path) => apply!(Matches(path), span),
+        Matches(path) => apply!(Matches(model::FieldRef::new(path)), span),
+        NotEqual(path) => apply!(NotEqual(model::FieldRef::new(path)), span),
+        GreaterThan(path) => apply!(GreaterThan(model::FieldRef::new(path)), span),
+        GreaterThanOrEqual(path) => apply!(GreaterThanOrEqual(model::FieldRef::new(path)), span),
+        LessThan(path) => apply!(LessThan(model::FieldRef::new(path)), span),
+        LessThanOrEqual(path) => apply!(LessThanOrEqual(model::FieldRef::new(path)), span),
         Range(v) => apply!(Range(check_range_not_ord(v)?), span),
         Contains(v) => apply!(Contains(v), span),
         Prefix(v) => apply!(Prefix(v), span),
@@ -405,6 +531,12 @@ fn check_rule(
                 return Err(error);
             }
         }
+        Groups(_) => {
+            return Err(syn::Error::new(
+                span,
+                "rule `groups` may only be used at the top level of an attribute",
+            ))
+        }
         If(if_rule) => {
             let mut conditional_rule_set = model::RuleSet::empty();
             let mut error = None;
@@ -11,24 +11,144 @@ pub fn emit(input: model::Validate) -> TokenStream2 {
     input.to_token_stream()
 }
 
+/// Selects which method is being generated.
+///
+/// `Report` emits `validate_into`, which collects errors and their paths into a `Report`.
+/// `Check` emits `is_valid_with`, which calls the rules' `is_valid` functions and stops at the first failure,
+/// without ever constructing an `Error` or a `Path`.
+/// `Async` emits `AsyncValidate::validate_into_async`, which is `Report` plus `custom_async` rules,
+/// and awaits nested values instead of calling `validate_into` on them.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Report,
+    Check,
+    Async,
+}
+
+impl Mode {
+    fn fail(self, error: TokenStream2) -> TokenStream2 {
+        match self {
+            Mode::Report | Mode::Async => quote! {
+                __garde_report.append(__garde_path(), #error);
+                if __garde_report.is_full() {
+                    return;
+                }
+            },
+            Mode::Check => quote! {
+                __garde_valid = false;
+                return;
+            },
+        }
+    }
+}
+
 impl ToTokens for model::Validate {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
+        if self.options.derive == model::Derive::Sanitize {
+            return Sanitize(self).to_tokens(tokens);
+        }
+
         let ident = &self.ident;
         let (context_ty, context_ident) = &self.context;
         let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
         let ty = Type {
+            mode: match self.options.derive {
+                model::Derive::AsyncValidate => Mode::Async,
+                _ => Mode::Report,
+            },
+            is_transparent: self.is_transparent,
+            kind: &self.kind,
+        };
+        let check_ty = Type {
+            mode: Mode::Check,
             is_transparent: self.is_transparent,
             kind: &self.kind,
         };
 
+        let rules = crate::introspect::Rules(self);
+
         let mut custom_rules = TokenStream2::new();
+        let mut check_custom_rules = TokenStream2::new();
         for custom_rule in &self.custom_rules {
             quote! {
                 if let Err(__garde_error) = (#custom_rule)(self, &__garde_user_ctx) {
                     __garde_report.append(__garde_path(), __garde_error);
+                    if __garde_report.is_full() {
+                        return;
+                    }
+                }
+            }
+            .to_tokens(&mut custom_rules);
+            quote! {
+                if let Err::<_, ::garde::Error>(_) = (#custom_rule)(self, &__garde_user_ctx) {
+                    __garde_valid = false;
+                    return;
+                }
+            }
+            .to_tokens(&mut check_custom_rules);
+        }
+        // `Reporter::at` takes fields by their Rust name, so it needs their aliases to build the same paths.
+        let aliases = match &self.kind {
+            model::ValidateKind::Struct(model::ValidateVariant::Struct(fields)) => fields
+                .iter()
+                .filter_map(|(ident, field)| {
+                    let alias = field.alias.as_ref()?;
+                    let ident = ident.to_string();
+                    Some(quote!((#ident, #alias)))
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        for report_rule in &self.report_rules {
+            quote! {
+                (#report_rule)(
+                    self,
+                    &__garde_user_ctx,
+                    &mut ::garde::error::Reporter::new(&mut __garde_path, __garde_report)
+                        .with_aliases(&[#(#aliases),*]),
+                );
+                if __garde_report.is_full() {
+                    return;
                 }
             }
             .to_tokens(&mut custom_rules);
+            quote! {{
+                let mut __garde_report = ::garde::error::Report::with_limit(1);
+                (#report_rule)(
+                    self,
+                    &__garde_user_ctx,
+                    &mut ::garde::error::Reporter::new(&mut ::garde::Path::empty, &mut __garde_report),
+                );
+                if !__garde_report.is_empty() {
+                    __garde_valid = false;
+                    return;
+                }
+            }}
+            .to_tokens(&mut check_custom_rules);
+        }
+
+        if self.options.derive == model::Derive::AsyncValidate {
+            quote! {
+                impl #impl_generics ::garde::async_validate::AsyncValidate for #ident #ty_generics #where_clause {
+                    type Context = #context_ty ;
+
+                    #[allow(clippy::needless_borrow)]
+                    fn validate_into_async(
+                        &self,
+                        #context_ident: &Self::Context,
+                        mut __garde_path: &mut (dyn FnMut() -> ::garde::Path + ::core::marker::Send),
+                        __garde_report: &mut ::garde::error::Report,
+                    ) -> impl ::core::future::Future<Output = ()> {
+                        async move {
+                            let __garde_user_ctx = &#context_ident;
+                            #custom_rules
+                            #ty
+                        }
+                    }
+                }
+            }
+            .to_tokens(tokens);
+            return;
         }
 
         quote! {
@@ -46,24 +166,45 @@ impl ToTokens for model::Validate {
                     #custom_rules
                     #ty
                 }
+
+                #[allow(clippy::needless_borrow, clippy::redundant_closure_call, unused_mut)]
+                fn is_valid_with(&self, #context_ident: &Self::Context) -> bool {
+                    let __garde_user_ctx = &#context_ident;
+                    let mut __garde_valid = true;
+                    (|| {
+                        #check_custom_rules
+                        #check_ty
+                    })();
+                    __garde_valid
+                }
+
+                fn rules() -> &'static [::garde::introspect::FieldRules] {
+                    #rules
+                }
             }
         }
-        .to_tokens(tokens)
+        .to_tokens(tokens);
+
+        #[cfg(feature = "json-schema")]
+        crate::schema::Schema(self).to_tokens(tokens);
     }
 }
 
 struct Type<'a> {
+    mode: Mode,
     is_transparent: bool,
     kind: &'a model::ValidateKind,
 }
 
 impl ToTokens for Type<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let mode = self.mode;
         let is_transparent = self.is_transparent;
         match &self.kind {
             model::ValidateKind::Struct(variant) => {
                 let bindings = Bindings(variant);
                 let validation = Variant {
+                    mode,
                     is_transparent,
                     variant,
                 };
@@ -78,6 +219,7 @@ impl ToTokens for Type<'_> {
                     if let Some(variant) = variant {
                         let bindings = Bindings(variant);
                         let validation = Variant {
+                            mode,
                             is_transparent,
                             variant,
                         };
@@ -100,16 +242,19 @@ impl ToTokens for Type<'_> {
 }
 
 struct Variant<'a> {
+    mode: Mode,
     is_transparent: bool,
     variant: &'a model::ValidateVariant,
 }
 
 impl ToTokens for Variant<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let mode = self.mode;
         let is_transparent = self.is_transparent;
         match &self.variant {
             model::ValidateVariant::Struct(fields) => {
                 let fields = Struct {
+                    mode,
                     is_transparent,
                     fields,
                 };
@@ -117,6 +262,7 @@ impl ToTokens for Variant<'_> {
             }
             model::ValidateVariant::Tuple(fields) => {
                 let fields = Tuple {
+                    mode,
                     is_transparent,
                     fields,
                 };
@@ -128,6 +274,7 @@ impl ToTokens for Variant<'_> {
 }
 
 struct Struct<'a> {
+    mode: Mode,
     is_transparent: bool,
     fields: &'a [(Ident, model::ValidateField)],
 }
@@ -135,10 +282,15 @@ struct Struct<'a> {
 impl ToTokens for Struct<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         Fields::new(
-            self.fields
-                .iter()
-                .map(|(key, field)| (Binding::Ident(key), field, key.to_string())),
-            |key, value| match self.is_transparent {
+            self.mode,
+            self.fields.iter().map(|(ident, field)| {
+                let key = match &field.alias {
+                    Some(alias) => alias.clone(),
+                    None => ident.to_string(),
+                };
+                (Binding::Ident(ident), field, key)
+            }),
+            |key, value| match self.is_transparent || self.mode == Mode::Check {
                 true => quote! {{
                     #value
                 }},
@@ -153,6 +305,7 @@ impl ToTokens for Struct<'_> {
 }
 
 struct Tuple<'a> {
+    mode: Mode,
     is_transparent: bool,
     fields: &'a [model::ValidateField],
 }
@@ -160,16 +313,20 @@ struct Tuple<'a> {
 impl ToTokens for Tuple<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         Fields::new(
-            self.fields
-                .iter()
-                .enumerate()
-                .map(|(index, field)| (Binding::Index(index), field, index)),
-            |index, value| match self.is_transparent {
+            self.mode,
+            self.fields.iter().enumerate().map(|(index, field)| {
+                let key = match &field.alias {
+                    Some(alias) => quote!(#alias),
+                    None => quote!(#index),
+                };
+                (Binding::Index(index), field, key)
+            }),
+            |key, value| match self.is_transparent || self.mode == Mode::Check {
                 true => quote! {{
                     #value
                 }},
                 false => quote! {{
-                    let mut __garde_path = ::garde::util::nested_path!(__garde_path, #index);
+                    let mut __garde_path = ::garde::util::nested_path!(__garde_path, #key);
                     #value
                 }},
             },
@@ -179,30 +336,42 @@ impl ToTokens for Tuple<'_> {
 }
 
 struct RuleSet<'a> {
+    mode: Mode,
+    is_adapted: bool,
     rules_mod: &'a TokenStream2,
     rule_set: &'a model::RuleSet,
+    code: Option<&'a str>,
 }
 
 impl ToTokens for RuleSet<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let RuleSet {
+            mode,
+            is_adapted,
             rules_mod,
             rule_set,
-        } = self;
+            code,
+        } = *self;
 
         let outer = match rule_set.has_top_level_rules() {
             true => {
                 let rules = Rules {
+                    mode,
+                    is_adapted,
                     rules_mod,
                     rule_set,
+                    code,
                 };
                 Some(quote! {#rules})
             }
             false => None,
         };
         let inner = rule_set.inner.as_deref().map(|rule_set| Inner {
+            mode,
+            is_adapted,
             rules_mod,
             rule_set,
+            code,
         });
 
         match (outer, inner) {
@@ -221,37 +390,91 @@ impl ToTokens for RuleSet<'_> {
 }
 
 struct Inner<'a> {
+    mode: Mode,
+    is_adapted: bool,
     rules_mod: &'a TokenStream2,
     rule_set: &'a model::RuleSet,
+    code: Option<&'a str>,
 }
 
 impl ToTokens for Inner<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let Inner {
+            mode,
+            is_adapted,
             rules_mod,
             rule_set,
-        } = self;
+            code,
+        } = *self;
         let value = RuleSet {
+            mode,
+            is_adapted,
             rules_mod,
             rule_set,
+            code,
         };
 
-        quote! {
-            #rules_mod::inner::apply(
-                &*__garde_binding,
-                |__garde_binding, __garde_inner_key| {
-                    let mut __garde_path = ::garde::util::nested_path!(__garde_path, __garde_inner_key);
-                    #value
+        match mode {
+            Mode::Report | Mode::Async => quote! {
+                #rules_mod::inner::apply(
+                    &*__garde_binding,
+                    |__garde_binding, __garde_inner_key| {
+                        if __garde_report.is_full() {
+                            return;
+                        }
+                        let mut __garde_path = ::garde::util::nested_path!(__garde_path, __garde_inner_key);
+                        #value
+                    }
+                );
+                if __garde_report.is_full() {
+                    return;
                 }
-            );
+            },
+            Mode::Check => quote! {
+                #rules_mod::inner::apply(
+                    &*__garde_binding,
+                    |__garde_binding, _| {
+                        if !__garde_valid {
+                            return;
+                        }
+                        #value
+                    }
+                );
+                if !__garde_valid {
+                    return;
+                }
+            },
         }
         .to_tokens(tokens)
     }
 }
 
 struct Rules<'a> {
+    mode: Mode,
+    is_adapted: bool,
     rules_mod: &'a TokenStream2,
     rule_set: &'a model::RuleSet,
+    code: Option<&'a str>,
+}
+
+/// Emits the `name, value` arguments for a rule which refers to another field,
+/// or `name, label, value` for the rule's `apply_labeled` if the field has a label.
+struct FieldRef<'a> {
+    field: &'a model::FieldRef,
+    labeled: bool,
+}
+
+impl ToTokens for FieldRef<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let path = &self.field.path;
+        match &self.field.label {
+            Some(label) if self.labeled => {
+                quote!(stringify!(#path), ::core::option::Option::Some(#label), &self.#path)
+            }
+            _ => quote!(stringify!(#path), &self.#path),
+        }
+        .to_tokens(tokens)
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -272,22 +495,56 @@ impl ToTokens for Binding<'_> {
 impl ToTokens for Rules<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let Rules {
+            mode,
+            is_adapted,
             rules_mod,
             rule_set,
-        } = self;
+            code,
+        } = *self;
+
+        let error = match code {
+            Some(code) => quote!(__garde_error.with_code(#code)),
+            None => quote!(__garde_error),
+        };
 
         for custom_rule in rule_set.custom_rules.iter() {
-            quote! {
-                if let Err(__garde_error) = (#custom_rule)(&*__garde_binding, &__garde_user_ctx) {
-                    __garde_report.append(__garde_path(), __garde_error);
-                }
+            let fail = mode.fail(error.clone());
+            match mode {
+                Mode::Report | Mode::Async => quote! {
+                    if let Err(__garde_error) = (#custom_rule)(&*__garde_binding, &__garde_user_ctx) {
+                        #fail
+                    }
+                },
+                Mode::Check => quote! {
+                    if let Err::<_, ::garde::Error>(_) = (#custom_rule)(&*__garde_binding, &__garde_user_ctx) {
+                        #fail
+                    }
+                },
             }
             .to_tokens(tokens);
         }
 
-        for rule in rule_set.rules.iter() {
+        for (rule, message) in rule_set.rules.iter() {
             let name = TokenStream2::from_str(rule.name()).unwrap();
             use model::ValidateRule::*;
+            // Labels only appear in error messages, so `Check` mode doesn't need them.
+            let labeled = mode != Mode::Check
+                && match rule {
+                    Matches(field)
+                    | NotEqual(field)
+                    | RequiredWith(field)
+                    | RequiredWithout(field)
+                    | GreaterThan(field)
+                    | GreaterThanOrEqual(field)
+                    | LessThan(field)
+                    | LessThanOrEqual(field) => field.label.is_some(),
+                    _ => false,
+                };
+            let apply = match labeled {
+                true => quote!(apply_labeled),
+                false => quote!(apply),
+            };
+            let field_ref = |field| FieldRef { field, labeled };
             let args = match rule {
                 Ascii | Alphanumeric | Email | Url | CreditCard | PhoneNumber | Required => {
                     quote!(())
@@ -319,8 +576,28 @@ impl ToTokens for Rules<'_> {
                         quote!((#equal, #equal))
                     }
                 },
-                Matches(path) => {
-                    quote!((stringify!(#path), &self.#path))
+                RequiredIf(cond) => {
+                    quote_spanned!(cond.span() => (#cond,))
+                }
+                Matches(field) | NotEqual(field) | RequiredWith(field) | RequiredWithout(field) => {
+                    let field = field_ref(field);
+                    quote!((#field))
+                }
+                GreaterThan(field) => {
+                    let field = field_ref(field);
+                    quote!((#rules_mod::compare::Comparison::GreaterThan, #field))
+                }
+                GreaterThanOrEqual(field) => {
+                    let field = field_ref(field);
+                    quote!((#rules_mod::compare::Comparison::GreaterThanOrEqual, #field))
+                }
+                LessThan(field) => {
+                    let field = field_ref(field);
+                    quote!((#rules_mod::compare::Comparison::LessThan, #field))
+                }
+                LessThanOrEqual(field) => {
+                    let field = field_ref(field);
+                    quote!((#rules_mod::compare::Comparison::LessThanOrEqual, #field))
                 }
                 Range(range) => match range {
                     model::ValidateRange::GreaterThan(min) => quote!((Some(#min), None)),
@@ -361,19 +638,61 @@ impl ToTokens for Rules<'_> {
                 },
             };
 
-            quote! {
-                if let Err(__garde_error) = (#rules_mod::#name::apply)(&*__garde_binding, #args) {
-                    __garde_report.append(__garde_path(), __garde_error);
+            let error = match message {
+                Some(model::Message::Fmt(message)) => {
+                    let message = &message.value;
+                    quote!(#error.with_message(#message))
                 }
+                Some(model::Message::Func(func)) => quote! {{
+                    let __garde_message = (#func)(__garde_error.params(), &__garde_user_ctx);
+                    #error.with_message(__garde_message)
+                }},
+                None => error.clone(),
+            };
+
+            let fail = mode.fail(error);
+            match (mode, is_adapted) {
+                (Mode::Report | Mode::Async, _) => quote! {
+                    if let Err(__garde_error) = (#rules_mod::#name::#apply)(&*__garde_binding, #args) {
+                        #fail
+                    }
+                },
+                // Adapters are not required to provide `is_valid`, so fall back to `apply`.
+                (Mode::Check, true) => quote! {
+                    if (#rules_mod::#name::apply)(&*__garde_binding, #args).is_err() {
+                        #fail
+                    }
+                },
+                (Mode::Check, false) => quote! {
+                    if !(#rules_mod::#name::is_valid)(&*__garde_binding, #args) {
+                        #fail
+                    }
+                },
             }
             .to_tokens(tokens)
         }
 
+        // These run after the other rules, so that I/O is only performed once the cheap checks are done.
+        if mode == Mode::Async {
+            for custom_rule in rule_set.custom_async_rules.iter() {
+                let fail = mode.fail(error.clone());
+                quote! {
+                    if let Err(__garde_error) = (#custom_rule)(&*__garde_binding, &__garde_user_ctx).await {
+                        #fail
+                    }
+                }
+                .to_tokens(tokens);
+            }
+        }
+
         for cond_rule_set in &rule_set.conditional_rule_sets {
             let condition = &cond_rule_set.condition;
             let cond_rules = RuleSet {
+                mode,
+                is_adapted,
                 rules_mod,
                 rule_set: &cond_rule_set.rule_set,
+                code,
             };
             quote! {
                 if #condition {
@@ -385,11 +704,11 @@ impl ToTokens for Rules<'_> {
     }
 }
 
-struct Fields<I, F>(RefCell<Option<I>>, F);
+struct Fields<I, F>(Mode, RefCell<Option<I>>, F);
 
 impl<I, F> Fields<I, F> {
-    fn new(iter: I, f: F) -> Self {
-        Self(RefCell::new(Some(iter)), f)
+    fn new(mode: Mode, iter: I, f: F) -> Self {
+        Self(mode, RefCell::new(Some(iter)), f)
     }
 }
 
@@ -399,11 +718,12 @@ where
     F: Fn(Extra, TokenStream2) -> TokenStream2,
 {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
-        let fields = match self.0.borrow_mut().take() {
+        let mode = self.0;
+        let fields = match self.1.borrow_mut().take() {
             Some(v) => v,
             None => return,
         };
-        let fields = fields.filter(|(_, field, _)| field.skip.is_none());
+        let fields = fields.filter(|(_, field, _)| field.is_validated());
         let default_rules_mod = quote!(::garde::rules);
         for (binding, field, extra) in fields {
             let field_adapter = field
@@ -415,35 +735,60 @@ where
                 Some(_) => &field_adapter,
                 None => &default_rules_mod,
             };
+            let is_adapted = field.adapter.is_some();
+            let code = field.code.as_deref();
             let rules = Rules {
+                mode,
+                is_adapted,
                 rules_mod,
                 rule_set: &field.rule_set,
+                code,
             };
             let outer = match field.has_top_level_rules() {
                 true => Some(quote! {{#rules}}),
                 false => None,
             };
-            let inner = match (&field.dive, &field.rule_set.inner) {
-                (Some((_, None)), None) => Some(quote! {
+            let dive = |ctx: TokenStream2| match mode {
+                Mode::Report => quote! {
                     ::garde::validate::Validate::validate_into(
                         &*__garde_binding,
-                        __garde_user_ctx,
+                        #ctx,
                         &mut __garde_path,
                         __garde_report,
                     );
-                }),
-                (Some((_, Some(ctx))), None) => Some(quote! {
-                    ::garde::validate::Validate::validate_into(
+                    if __garde_report.is_full() {
+                        return;
+                    }
+                },
+                Mode::Check => quote! {
+                    if !::garde::validate::Validate::is_valid_with(&*__garde_binding, #ctx) {
+                        __garde_valid = false;
+                        return;
+                    }
+                },
+                Mode::Async => quote! {
+                    ::garde::async_validate::AsyncValidate::validate_into_async(
                         &*__garde_binding,
-                        &#ctx,
+                        #ctx,
                         &mut __garde_path,
                         __garde_report,
-                    );
-                }),
+                    )
+                    .await;
+                    if __garde_report.is_full() {
+                        return;
+                    }
+                },
+            };
+            let inner = match (&field.dive, &field.rule_set.inner) {
+                (Some((_, None)), None) => Some(dive(quote!(__garde_user_ctx))),
+                (Some((_, Some(ctx))), None) => Some(dive(quote!(&#ctx))),
                 (None, Some(inner)) => Some(
                     Inner {
+                        mode,
+                        is_adapted,
                         rules_mod,
                         rule_set: inner,
+                        code,
                     }
                     .to_token_stream(),
                 ),
@@ -454,22 +799,28 @@ where
 
             let value = match (outer, inner) {
                 (Some(outer), Some(inner)) => quote! {
-                    let __garde_binding = &*#binding;
                     #inner
                     #outer
                 },
-                (None, Some(inner)) => quote! {
+                (None, Some(inner)) => inner,
+                (Some(outer), None) => outer,
+                (None, None) => unreachable!("field should already be skipped"),
+            };
+            // Absent fields are not validated at all in partial mode, not even by `custom` rules.
+            let value = match field.is_option() {
+                true => quote! {
                     let __garde_binding = &*#binding;
-                    #inner
+                    if !::garde::validate::is_absent(__garde_binding) {
+                        #value
+                    }
                 },
-                (Some(outer), None) => quote! {
+                false => quote! {
                     let __garde_binding = &*#binding;
-                    #outer
+                    #value
                 },
-                (None, None) => unreachable!("field should already be skipped"),
             };
 
-            let add = &self.1;
+            let add = &self.2;
 
             add(extra, value).to_tokens(tokens)
         }
@@ -484,7 +835,7 @@ impl ToTokens for Bindings<'_> {
             model::ValidateVariant::Struct(fields) => {
                 let names = fields
                     .iter()
-                    .filter(|field| field.1.skip.is_none())
+                    .filter(|field| field.1.is_validated())
                     .map(|field| &field.0)
                     .collect::<Vec<_>>();
                 let rest = if names.len() != fields.len() {
@@ -497,7 +848,7 @@ impl ToTokens for Bindings<'_> {
             }
             model::ValidateVariant::Tuple(fields) => {
                 let bindings = fields.iter().enumerate().map(|(i, field)| {
-                    if field.skip.is_none() {
+                    if field.is_validated() {
                         IndexBinding(i).to_token_stream()
                     } else {
                         quote!(_)
@@ -517,3 +868,129 @@ impl ToTokens for IndexBinding {
         format_ident!("_{}", self.0).to_tokens(tokens)
     }
 }
+
+struct Sanitize<'a>(&'a model::Validate);
+
+impl ToTokens for Sanitize<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let model::Validate {
+            ident,
+            generics,
+            kind,
+            ..
+        } = self.0;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let body = match kind {
+            model::ValidateKind::Struct(variant) => {
+                let bindings = SanitizeBindings(variant);
+                let fields = SanitizeFields(variant);
+                quote! {
+                    let Self #bindings = self;
+                    #fields
+                }
+            }
+            model::ValidateKind::Enum(variants) => {
+                let variants = variants.iter().map(|(name, variant)| match variant {
+                    Some(variant) => {
+                        let bindings = SanitizeBindings(variant);
+                        let fields = SanitizeFields(variant);
+                        quote!(Self::#name #bindings => {#fields})
+                    }
+                    None => quote!(Self::#name => {}),
+                });
+                quote! {
+                    match self {
+                        #(#variants,)*
+                    }
+                }
+            }
+        };
+
+        quote! {
+            impl #impl_generics ::garde::sanitize::Sanitize for #ident #ty_generics #where_clause {
+                fn sanitize(&mut self) {
+                    #body
+                }
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
+
+struct SanitizeFields<'a>(&'a model::ValidateVariant);
+
+impl ToTokens for SanitizeFields<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let fields: Vec<(Binding, &model::ValidateField)> = match &self.0 {
+            model::ValidateVariant::Struct(fields) => fields
+                .iter()
+                .map(|(ident, field)| (Binding::Ident(ident), field))
+                .collect(),
+            model::ValidateVariant::Tuple(fields) => fields
+                .iter()
+                .enumerate()
+                .map(|(index, field)| (Binding::Index(index), field))
+                .collect(),
+        };
+
+        for (binding, field) in fields {
+            if !field.is_sanitized() {
+                continue;
+            }
+            let sanitizers = field.sanitizers.iter().map(|sanitizer| match sanitizer {
+                model::Sanitizer::Trim => quote!(::garde::sanitize::trim::apply(__garde_binding);),
+                model::Sanitizer::Lowercase => {
+                    quote!(::garde::sanitize::lowercase::apply(__garde_binding);)
+                }
+                model::Sanitizer::Uppercase => {
+                    quote!(::garde::sanitize::uppercase::apply(__garde_binding);)
+                }
+                model::Sanitizer::CollapseWhitespace => {
+                    quote!(::garde::sanitize::collapse_whitespace::apply(__garde_binding);)
+                }
+                model::Sanitizer::Custom(func) => quote!((#func)(__garde_binding);),
+            });
+            let dive = field
+                .dive
+                .as_ref()
+                .map(|_| quote!(::garde::sanitize::Sanitize::sanitize(__garde_binding);));
+
+            quote! {{
+                let __garde_binding = &mut *#binding;
+                #(#sanitizers)*
+                #dive
+            }}
+            .to_tokens(tokens)
+        }
+    }
+}
+
+struct SanitizeBindings<'a>(&'a model::ValidateVariant);
+
+impl ToTokens for SanitizeBindings<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        match &self.0 {
+            model::ValidateVariant::Struct(fields) => {
+                let names = fields
+                    .iter()
+                    .filter(|field| field.1.is_sanitized())
+                    .map(|field| &field.0);
+
+                quote!( { #(#names,)* .. } )
+            }
+            model::ValidateVariant::Tuple(fields) => {
+                let bindings = fields.iter().enumerate().map(|(i, field)| {
+                    if field.is_sanitized() {
+                        IndexBinding(i).to_token_stream()
+                    } else {
+                        quote!(_)
+                    }
+                });
+
+                quote!( ( #(#bindings,)* ) )
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
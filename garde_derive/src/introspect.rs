@@ -0,0 +1,180 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, ToTokens};
+
+use crate::model;
+
+/// Emits the `&'static [FieldRules]` returned by `Validate::rules`.
+///
+/// Everything is a constant expression, so the slice is promoted to a `'static`.
+pub struct Rules<'a>(pub &'a model::Validate);
+
+impl ToTokens for Rules<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let mut fields = Vec::new();
+        match &self.0.kind {
+            model::ValidateKind::Struct(variant) => push_fields(&mut fields, None, variant),
+            model::ValidateKind::Enum(variants) => {
+                for (name, variant) in variants {
+                    if let Some(variant) = variant {
+                        push_fields(&mut fields, Some(name.to_string()), variant);
+                    }
+                }
+            }
+        }
+        quote!(&[#(#fields),*]).to_tokens(tokens)
+    }
+}
+
+fn push_fields(
+    out: &mut Vec<TokenStream2>,
+    variant: Option<String>,
+    fields: &model::ValidateVariant,
+) {
+    let field = |name: String, field: &model::ValidateField| {
+        let variant = option(variant.as_ref());
+        let rule_set = RuleSet(&field.rule_set);
+        let ty = &field.ty;
+        let dive = option(
+            field
+                .dive
+                .as_ref()
+                .map(|_| quote!(<#ty as ::garde::Validate>::rules)),
+        );
+        quote! {
+            ::garde::introspect::FieldRules {
+                name: #name,
+                variant: #variant,
+                rules: #rule_set,
+                dive: #dive,
+            }
+        }
+    };
+    match fields {
+        model::ValidateVariant::Struct(fields) => {
+            for (ident, f) in fields.iter().filter(|(_, f)| f.is_validated()) {
+                let name = match &f.alias {
+                    Some(alias) => alias.clone(),
+                    None => ident.to_string(),
+                };
+                out.push(field(name, f));
+            }
+        }
+        model::ValidateVariant::Tuple(fields) => {
+            for (index, f) in fields.iter().enumerate().filter(|(_, f)| f.is_validated()) {
+                let name = match &f.alias {
+                    Some(alias) => alias.clone(),
+                    None => index.to_string(),
+                };
+                out.push(field(name, f));
+            }
+        }
+    }
+}
+
+struct RuleSet<'a>(&'a model::RuleSet);
+
+impl ToTokens for RuleSet<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let model::RuleSet {
+            rules,
+            custom_rules,
+            custom_async_rules,
+            inner,
+            conditional_rule_sets,
+        } = self.0;
+        let rules = rules.keys().map(Rule);
+        let custom = custom_rules.iter().chain(custom_async_rules).map(source);
+        let inner = option(inner.as_deref().map(|inner| {
+            let inner = RuleSet(inner);
+            quote!(&#inner)
+        }));
+        let conditional = conditional_rule_sets.iter().map(|conditional| {
+            let condition = source(&conditional.condition);
+            let rule_set = RuleSet(&conditional.rule_set);
+            quote! {
+                ::garde::introspect::ConditionalRuleSet {
+                    condition: #condition,
+                    rule_set: #rule_set,
+                }
+            }
+        });
+        quote! {
+            ::garde::introspect::RuleSet {
+                rules: &[#(#rules),*],
+                custom: &[#(#custom),*],
+                inner: #inner,
+                conditional: &[#(#conditional),*],
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
+
+struct Rule<'a>(&'a model::ValidateRule);
+
+impl ToTokens for Rule<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        use model::ValidateRule::*;
+
+        let name = self.0.name();
+        let args: Vec<(&str, String)> = match self.0 {
+            Required | Ascii | Alphanumeric | Email | Url | Ip | CreditCard | PhoneNumber => {
+                vec![]
+            }
+            IpV4 => vec![("kind", "v4".into())],
+            IpV6 => vec![("kind", "v6".into())],
+            RequiredIf(condition) => vec![("condition", source(condition))],
+            RequiredWith(field) | RequiredWithout(field) | Matches(field) | NotEqual(field) => {
+                vec![("field", source(&field.path))]
+            }
+            GreaterThan(field) => vec![("op", ">".into()), ("field", source(&field.path))],
+            GreaterThanOrEqual(field) => vec![("op", ">=".into()), ("field", source(&field.path))],
+            LessThan(field) => vec![("op", "<".into()), ("field", source(&field.path))],
+            LessThanOrEqual(field) => vec![("op", "<=".into()), ("field", source(&field.path))],
+            LengthSimple(range)
+            | LengthBytes(range)
+            | LengthChars(range)
+            | LengthGraphemes(range)
+            | LengthUtf16(range) => range_args(range, |v| match v {
+                model::Either::Left(v) => v.to_string(),
+                model::Either::Right(v) => source(v),
+            }),
+            Range(range) => range_args(range, source),
+            Contains(value) | Prefix(value) | Suffix(value) => vec![("value", source(value))],
+            #[cfg(feature = "regex")]
+            Pattern(model::ValidatePattern::Lit(pattern)) => vec![("pattern", pattern.clone())],
+            Pattern(model::ValidatePattern::Expr(pattern)) => vec![("pattern", source(pattern))],
+        };
+        let args = args.iter().map(|(key, value)| quote!((#key, #value)));
+        quote! {
+            ::garde::introspect::Rule {
+                name: #name,
+                args: &[#(#args),*],
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
+
+fn range_args<T>(
+    range: &model::ValidateRange<T>,
+    f: impl Fn(&T) -> String,
+) -> Vec<(&'static str, String)> {
+    match range {
+        model::ValidateRange::GreaterThan(min) => vec![("min", f(min))],
+        model::ValidateRange::LowerThan(max) => vec![("max", f(max))],
+        model::ValidateRange::Between(min, max) => vec![("min", f(min)), ("max", f(max))],
+        model::ValidateRange::Equal(equal) => vec![("equal", f(equal))],
+    }
+}
+
+fn source(tokens: &impl ToTokens) -> String {
+    tokens.to_token_stream().to_string()
+}
+
+fn option<T: ToTokens>(v: Option<T>) -> TokenStream2 {
+    match v {
+        Some(v) => quote!(::core::option::Option::Some(#v)),
+        None => quote!(::core::option::Option::None),
+    }
+}
@@ -1,6 +1,9 @@
 mod check;
 mod emit;
+mod introspect;
 mod model;
+#[cfg(feature = "json-schema")]
+mod schema;
 mod syntax;
 mod util;
 
@@ -10,12 +13,26 @@ use syn::DeriveInput;
 
 #[proc_macro_derive(Validate, attributes(garde))]
 pub fn derive_validate(input: TokenStream) -> TokenStream {
+    derive(input, model::Derive::Validate)
+}
+
+#[proc_macro_derive(AsyncValidate, attributes(garde))]
+pub fn derive_async_validate(input: TokenStream) -> TokenStream {
+    derive(input, model::Derive::AsyncValidate)
+}
+
+#[proc_macro_derive(Sanitize, attributes(garde))]
+pub fn derive_sanitize(input: TokenStream) -> TokenStream {
+    derive(input, model::Derive::Sanitize)
+}
+
+fn derive(input: TokenStream, derive: model::Derive) -> TokenStream {
     let input = syn::parse_macro_input!(input as DeriveInput);
     let input = match syntax::parse(input) {
         Ok(v) => v,
         Err(e) => return e.into_compile_error().into(),
     };
-    let input = match check::check(input) {
+    let input = match check::check(input, derive) {
         Ok(v) => v,
         Err(e) => return e.into_compile_error().into(),
     };
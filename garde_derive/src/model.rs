@@ -1,4 +1,4 @@
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::BTreeMap;
 
 use proc_macro2::{Ident, Span};
 use syn::{Expr, Generics, Path, Type};
@@ -16,6 +16,7 @@ pub enum Attr {
     AllowUnvalidated,
     Transparent,
     Custom(Expr),
+    Report(Expr),
 }
 
 impl Attr {
@@ -33,6 +34,7 @@ impl Attr {
             Attr::AllowUnvalidated => "allow_unvalidated",
             Attr::Transparent => "transparent",
             Attr::Custom(..) => "custom",
+            Attr::Report(..) => "report",
         }
     }
 }
@@ -64,24 +66,28 @@ pub struct Field {
     pub rules: Vec<RawRule>,
 }
 
-// pub enum Message {
-//     Fmt(Str),
-//     Func(Expr),
-// }
+pub enum Message {
+    Fmt(Str),
+    Func(Expr),
+}
 
 pub struct RawRule {
     pub span: Span,
     pub kind: RawRuleKind,
+    pub message: Option<Message>,
 }
 
 pub enum RawRuleKind {
     Skip,
     Adapt(Path),
     Rename(Str),
-    // Message(Message),
+    Label(Str),
     Code(Str),
     Dive(Option<Expr>),
     Required,
+    RequiredIf(Expr),
+    RequiredWith(Path),
+    RequiredWithout(Path),
     Ascii,
     Alphanumeric,
     Email,
@@ -93,14 +99,26 @@ pub enum RawRuleKind {
     PhoneNumber,
     Length(RawLength),
     Matches(Path),
+    NotEqual(Path),
+    GreaterThan(Path),
+    GreaterThanOrEqual(Path),
+    LessThan(Path),
+    LessThanOrEqual(Path),
     Range(Range<Expr>),
     Contains(Expr),
     Prefix(Expr),
     Suffix(Expr),
     Pattern(Pattern),
     Custom(Expr),
+    CustomAsync(Expr),
+    Trim,
+    Lowercase,
+    Uppercase,
+    CollapseWhitespace,
+    Sanitize(Expr),
     Inner(List<RawRule>),
     If(IfRule),
+    Groups(List<Path>),
 }
 
 pub struct IfRule {
@@ -169,6 +187,7 @@ pub struct Validate {
     pub is_transparent: bool,
     pub kind: ValidateKind,
     pub custom_rules: Vec<Expr>,
+    pub report_rules: Vec<Expr>,
     // I don't know why Rust thinks this is unused.
     // It's both read and written, grep for `.allow_unvalidated`.
     #[allow(dead_code)]
@@ -177,6 +196,15 @@ pub struct Validate {
 
 pub struct Options {
     pub allow_unvalidated: bool,
+    pub derive: Derive,
+}
+
+/// The derive macro which is being expanded.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Derive {
+    Validate,
+    AsyncValidate,
+    Sanitize,
 }
 
 pub enum ValidateKind {
@@ -190,11 +218,21 @@ pub struct ValidateField {
     pub adapter: Option<Path>,
     pub skip: Option<Span>,
     pub alias: Option<String>,
-    // pub message: Option<Message>,
+    pub label: Option<String>,
     pub code: Option<String>,
 
     pub dive: Option<(Span, Option<Expr>)>,
     pub rule_set: RuleSet,
+    pub sanitizers: Vec<Sanitizer>,
+}
+
+/// Sanitizers are kept in the order they were written in, because that order matters.
+pub enum Sanitizer {
+    Trim,
+    Lowercase,
+    Uppercase,
+    CollapseWhitespace,
+    Custom(Expr),
 }
 
 pub struct ConditionalRuleSet {
@@ -204,17 +242,47 @@ pub struct ConditionalRuleSet {
 
 impl ValidateField {
     pub fn is_empty(&self) -> bool {
-        self.dive.is_none() && self.rule_set.is_empty()
+        !self.has_validation() && self.sanitizers.is_empty()
+    }
+
+    /// Returns `true` if the field has `dive` or any validation rules, ignoring sanitizers.
+    pub fn has_validation(&self) -> bool {
+        self.dive.is_some() || !self.rule_set.is_empty()
+    }
+
+    /// Returns `true` if the field has anything to validate, as opposed to only sanitizers.
+    pub fn is_validated(&self) -> bool {
+        self.skip.is_none() && self.has_validation()
+    }
+
+    /// Returns `true` if the field has anything to sanitize.
+    ///
+    /// `skip` only skips validation, so the sanitizers of a skipped field still run.
+    pub fn is_sanitized(&self) -> bool {
+        !self.sanitizers.is_empty() || (self.skip.is_none() && self.dive.is_some())
     }
 
     pub fn has_top_level_rules(&self) -> bool {
         self.rule_set.has_top_level_rules()
     }
+
+    /// Returns `true` if the field's type is written as an `Option`.
+    pub fn is_option(&self) -> bool {
+        match &self.ty {
+            Type::Path(ty) if ty.qself.is_none() => ty
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "Option"),
+            _ => false,
+        }
+    }
 }
 
 pub struct RuleSet {
-    pub rules: BTreeSet<ValidateRule>,
+    pub rules: BTreeMap<ValidateRule, Option<Message>>,
     pub custom_rules: Vec<Expr>,
+    pub custom_async_rules: Vec<Expr>,
     pub inner: Option<Box<RuleSet>>,
     pub conditional_rule_sets: Vec<ConditionalRuleSet>,
 }
@@ -222,8 +290,9 @@ pub struct RuleSet {
 impl RuleSet {
     pub fn empty() -> Self {
         Self {
-            rules: BTreeSet::new(),
+            rules: BTreeMap::new(),
             custom_rules: Vec::new(),
+            custom_async_rules: Vec::new(),
             inner: None,
             conditional_rule_sets: Vec::new(),
         }
@@ -237,15 +306,25 @@ impl RuleSet {
         inner_empty
             && self.rules.is_empty()
             && self.custom_rules.is_empty()
+            && self.custom_async_rules.is_empty()
             && self.conditional_rule_sets.is_empty()
     }
 
     pub fn has_top_level_rules(&self) -> bool {
         !self.rules.is_empty()
             || !self.custom_rules.is_empty()
+            || !self.custom_async_rules.is_empty()
             || !self.conditional_rule_sets.is_empty()
     }
 
+    pub fn first_async_rule(&self) -> Option<&Expr> {
+        self.custom_async_rules.first().or_else(|| {
+            self.conditional_rule_sets
+                .iter()
+                .find_map(|conditional| conditional.rule_set.first_async_rule())
+        })
+    }
+
     pub fn has_inner_rules(&self) -> bool {
         self.inner.is_some()
             || self
@@ -258,6 +337,9 @@ impl RuleSet {
 #[repr(u8)]
 pub enum ValidateRule {
     Required,
+    RequiredIf(Expr),
+    RequiredWith(FieldRef),
+    RequiredWithout(FieldRef),
     Ascii,
     Alphanumeric,
     Email,
@@ -272,7 +354,12 @@ pub enum ValidateRule {
     LengthChars(LengthRange),
     LengthGraphemes(LengthRange),
     LengthUtf16(LengthRange),
-    Matches(Path),
+    Matches(FieldRef),
+    NotEqual(FieldRef),
+    GreaterThan(FieldRef),
+    GreaterThanOrEqual(FieldRef),
+    LessThan(FieldRef),
+    LessThanOrEqual(FieldRef),
     Range(ValidateRange<Expr>),
     Contains(Expr),
     Prefix(Expr),
@@ -282,10 +369,26 @@ pub enum ValidateRule {
 
 type LengthRange = ValidateRange<Either<usize, Expr>>;
 
+/// Another field of the same struct, which a rule such as `matches` refers to.
+pub struct FieldRef {
+    pub path: Path,
+    /// The `label` of the referenced field, if it has one.
+    pub label: Option<String>,
+}
+
+impl FieldRef {
+    pub fn new(path: Path) -> Self {
+        Self { path, label: None }
+    }
+}
+
 impl ValidateRule {
     pub fn name(&self) -> &'static str {
         match self {
             ValidateRule::Required => "required",
+            ValidateRule::RequiredIf(_) => "required_if",
+            ValidateRule::RequiredWith(_) => "required_with",
+            ValidateRule::RequiredWithout(_) => "required_without",
             ValidateRule::Ascii => "ascii",
             ValidateRule::Alphanumeric => "alphanumeric",
             ValidateRule::Email => "email",
@@ -301,6 +404,11 @@ impl ValidateRule {
             ValidateRule::LengthGraphemes(_) => "length::graphemes",
             ValidateRule::LengthUtf16(_) => "length::utf16",
             ValidateRule::Matches(_) => "matches",
+            ValidateRule::NotEqual(_) => "not_equal",
+            ValidateRule::GreaterThan(_) => "compare",
+            ValidateRule::GreaterThanOrEqual(_) => "compare",
+            ValidateRule::LessThan(_) => "compare",
+            ValidateRule::LessThanOrEqual(_) => "compare",
             ValidateRule::Range(_) => "range",
             ValidateRule::Contains(_) => "contains",
             ValidateRule::Prefix(_) => "prefix",
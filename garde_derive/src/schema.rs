@@ -0,0 +1,244 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, ToTokens};
+use syn::Expr;
+
+use crate::model;
+
+/// Emits the `GardeSchema` impl for a `#[derive(Validate)]` type.
+pub struct Schema<'a>(pub &'a model::Validate);
+
+impl ToTokens for Schema<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let model::Validate {
+            ident,
+            generics,
+            is_transparent,
+            kind,
+            ..
+        } = self.0;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let body = match kind {
+            model::ValidateKind::Struct(variant) => Variant {
+                is_transparent: *is_transparent,
+                variant,
+            }
+            .to_token_stream(),
+            model::ValidateKind::Enum(variants) => {
+                // Variants are described using `serde`'s default, externally tagged representation.
+                let variants = variants.iter().map(|(name, variant)| {
+                    let name = name.to_string();
+                    match variant {
+                        Some(variant) => {
+                            let variant = Variant {
+                                is_transparent: false,
+                                variant,
+                            };
+                            quote!(::garde::schema::variant(#name, #variant))
+                        }
+                        None => quote!(::garde::schema::constant(#name)),
+                    }
+                });
+                quote!(::garde::schema::one_of(::std::vec![#(#variants),*]))
+            }
+        };
+
+        quote! {
+            impl #impl_generics ::garde::schema::GardeSchema for #ident #ty_generics #where_clause {
+                #[allow(unused_mut, unused_variables)]
+                fn json_schema() -> ::garde::schema::Value {
+                    #[allow(unused_imports)]
+                    use ::garde::schema::__private::{ViaFallback as _, ViaSchema as _};
+                    #body
+                }
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
+
+struct Variant<'a> {
+    is_transparent: bool,
+    variant: &'a model::ValidateVariant,
+}
+
+impl ToTokens for Variant<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        match self.variant {
+            model::ValidateVariant::Struct(fields) => {
+                let fields = fields
+                    .iter()
+                    .filter(|(_, field)| field.skip.is_none())
+                    .collect::<Vec<_>>();
+                if self.is_transparent && fields.len() == 1 {
+                    let field = Field(&fields[0].1);
+                    return quote!(#field.0).to_tokens(tokens);
+                }
+                let fields = fields.into_iter().map(|(ident, field)| {
+                    let key = match &field.alias {
+                        Some(alias) => alias.clone(),
+                        None => ident.to_string(),
+                    };
+                    let is_required = field
+                        .rule_set
+                        .rules
+                        .contains_key(&model::ValidateRule::Required);
+                    // `Option`s whose inner type has no schema are only recognized by their name.
+                    let is_option = field.is_option();
+                    let schema = Field(field);
+                    quote! {{
+                        let (__garde_schema, __garde_optional) = #schema;
+                        if #is_required || !(#is_option || __garde_optional) {
+                            __garde_required.push(#key);
+                        }
+                        __garde_properties.insert(#key.into(), __garde_schema);
+                    }}
+                });
+                quote! {{
+                    let mut __garde_properties = ::garde::schema::Map::new();
+                    let mut __garde_required = ::std::vec::Vec::new();
+                    #(#fields)*
+                    ::garde::schema::object(__garde_properties, __garde_required)
+                }}
+                .to_tokens(tokens)
+            }
+            // Skipped fields are kept in tuples, because leaving them out would shift the other fields.
+            model::ValidateVariant::Tuple(fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|field| {
+                        let schema = Field(field);
+                        quote!(#schema.0)
+                    })
+                    .collect::<Vec<_>>();
+                match fields.as_slice() {
+                    [field] => field.to_tokens(tokens),
+                    fields => {
+                        quote!(::garde::schema::tuple(::std::vec![#(#fields),*])).to_tokens(tokens)
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Evaluates to the `(schema, is_optional)` of a field, with its constraints applied.
+struct Field<'a>(&'a model::ValidateField);
+
+impl ToTokens for Field<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let ty = &self.0.ty;
+        let constraints = Constraints(&self.0.rule_set);
+        quote! {{
+            let (mut __garde_value, __garde_optional) =
+                (&&::garde::schema::__private::Wrap::<#ty>(::core::marker::PhantomData)).schema();
+            let __garde_schema = &mut __garde_value;
+            #constraints
+            (__garde_value, __garde_optional)
+        }}
+        .to_tokens(tokens)
+    }
+}
+
+struct Constraints<'a>(&'a model::RuleSet);
+
+impl ToTokens for Constraints<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        for rule in self.0.rules.keys() {
+            use model::ValidateRule::*;
+            match rule {
+                // The simple mode counts bytes for strings, so `length` only applies it to lists and maps.
+                LengthSimple(range) | LengthChars(range) => {
+                    let (min, max) = bounds(range);
+                    let min = literal_len(min);
+                    let max = literal_len(max);
+                    if min.is_some() || max.is_some() {
+                        let min = option(min);
+                        let max = option(max);
+                        let func = match rule {
+                            LengthChars(_) => quote!(::garde::schema::char_length),
+                            _ => quote!(::garde::schema::length),
+                        };
+                        quote!(#func(__garde_schema, #min, #max);).to_tokens(tokens)
+                    }
+                }
+                Range(range) => {
+                    let (min, max) = bounds(range);
+                    let min = min.filter(|v| is_literal(v));
+                    let max = max.filter(|v| is_literal(v));
+                    if min.is_some() || max.is_some() {
+                        let min = option(min.map(|v| quote!(::garde::schema::Value::from(#v))));
+                        let max = option(max.map(|v| quote!(::garde::schema::Value::from(#v))));
+                        quote!(::garde::schema::range(__garde_schema, #min, #max);)
+                            .to_tokens(tokens)
+                    }
+                }
+                Required => quote!(::garde::schema::non_null(__garde_schema);).to_tokens(tokens),
+                Email => {
+                    quote!(::garde::schema::format(__garde_schema, "email");).to_tokens(tokens)
+                }
+                Url => quote!(::garde::schema::format(__garde_schema, "uri");).to_tokens(tokens),
+                IpV4 => quote!(::garde::schema::format(__garde_schema, "ipv4");).to_tokens(tokens),
+                IpV6 => quote!(::garde::schema::format(__garde_schema, "ipv6");).to_tokens(tokens),
+                #[cfg(feature = "regex")]
+                Pattern(model::ValidatePattern::Lit(pattern)) => {
+                    quote!(::garde::schema::pattern(__garde_schema, #pattern);).to_tokens(tokens)
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(inner) = &self.0.inner {
+            let inner = Constraints(inner);
+            quote! {
+                if let Some(__garde_schema) = ::garde::schema::inner(__garde_schema) {
+                    #inner
+                }
+            }
+            .to_tokens(tokens)
+        }
+    }
+}
+
+fn bounds<T>(range: &model::ValidateRange<T>) -> (Option<&T>, Option<&T>) {
+    match range {
+        model::ValidateRange::GreaterThan(min) => (Some(min), None),
+        model::ValidateRange::LowerThan(max) => (None, Some(max)),
+        model::ValidateRange::Between(min, max) => (Some(min), Some(max)),
+        model::ValidateRange::Equal(equal) => (Some(equal), Some(equal)),
+    }
+}
+
+fn literal_len(v: Option<&model::Either<usize, Expr>>) -> Option<usize> {
+    match v {
+        Some(model::Either::Left(v)) => Some(*v),
+        Some(model::Either::Right(expr)) if is_literal(expr) => match expr {
+            Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(lit),
+                ..
+            }) => lit.base10_parse().ok(),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Only literals are emitted, because other expressions may refer to `self` or the context.
+fn is_literal(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(_) => true,
+        Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => matches!(**expr, Expr::Lit(_)),
+        _ => false,
+    }
+}
+
+fn option<T: ToTokens>(v: Option<T>) -> TokenStream2 {
+    match v {
+        Some(v) => quote!(::core::option::Option::Some(#v)),
+        None => quote!(::core::option::Option::None),
+    }
+}
@@ -1,8 +1,8 @@
 use std::collections::BTreeMap;
 
-use proc_macro2::{Ident, Span};
+use proc_macro2::{Delimiter, Ident, Spacing, Span, TokenStream as TokenStream2, TokenTree};
 use syn::ext::IdentExt;
-use syn::parse::Parse;
+use syn::parse::{Parse, Parser};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::As;
@@ -106,6 +106,11 @@ impl Parse for model::Attr {
                 syn::parenthesized!(content in input);
                 Ok(model::Attr::Custom(content.parse()?))
             }
+            "report" => {
+                let content;
+                syn::parenthesized!(content in input);
+                Ok(model::Attr::Report(content.parse()?))
+            }
             _ => Err(syn::Error::new(ident.span(), "unrecognized attribute")),
         }
     }
@@ -214,12 +219,17 @@ fn parse_field_attr_list(attrs: &[syn::Attribute]) -> syn::Result<Vec<model::Raw
         if attr.path().is_ident("garde") {
             match attr.parse_args_with(Punctuated::<_, syn::token::Comma>::parse_terminated) {
                 Ok(list) => {
+                    let mut attr_rules = Vec::new();
                     for rule in list {
                         match rule {
-                            ContinueOnFail::Ok(v) => rules.push(v),
+                            ContinueOnFail::Ok(v) => attr_rules.push(v),
                             ContinueOnFail::Err(e) => error.maybe_fold(e),
                         }
                     }
+                    match apply_groups(attr_rules) {
+                        Ok(v) => rules.extend(v),
+                        Err(e) => error.maybe_fold(e),
+                    }
                 }
                 Err(e) => error.maybe_fold(e),
             }
@@ -233,6 +243,88 @@ fn parse_field_attr_list(attrs: &[syn::Attribute]) -> syn::Result<Vec<model::Raw
     Ok(rules)
 }
 
+/// If the rules of a single attribute contain `groups(...)`, wraps the rest of
+/// them in a conditional rule which only applies while one of those groups is active.
+fn apply_groups(rules: Vec<model::RawRule>) -> syn::Result<Vec<model::RawRule>> {
+    let mut groups = None;
+    let mut rest = Vec::new();
+    for rule in rules {
+        match rule.kind {
+            model::RawRuleKind::Groups(list) => {
+                if groups.is_some() {
+                    return Err(syn::Error::new(rule.span, "duplicate rule `groups`"));
+                }
+                groups = Some((rule.span, list));
+            }
+            _ => rest.push(rule),
+        }
+    }
+
+    let Some((span, list)) = groups else {
+        return Ok(rest);
+    };
+    let mut error = None;
+    for rule in &rest {
+        if let Some(name) = field_level_rule_name(&rule.kind) {
+            error.maybe_fold(syn::Error::new(
+                rule.span,
+                format!(
+                    "`groups` only applies to validation rules, \
+                     move `{name}` to a separate `#[garde(...)]` attribute"
+                ),
+            ));
+        }
+    }
+    if let Some(error) = error {
+        return Err(error);
+    }
+    if list.contents.is_empty() {
+        return Err(syn::Error::new(
+            span,
+            "`groups` must contain at least one group",
+        ));
+    }
+    if rest.is_empty() {
+        return Err(syn::Error::new(
+            span,
+            "`groups` must be used together with at least one validation rule",
+        ));
+    }
+
+    let groups = list.contents.iter();
+    let condition = syn::parse_quote!(::garde::group::is_active(&[
+        #(::core::any::TypeId::of::<#groups>()),*
+    ]));
+    Ok(vec![model::RawRule {
+        span,
+        kind: model::RawRuleKind::If(model::IfRule {
+            condition,
+            rules: List { contents: rest },
+        }),
+        message: None,
+    }])
+}
+
+/// The name of `kind` if it applies to the whole field, rather than being a validation rule.
+fn field_level_rule_name(kind: &model::RawRuleKind) -> Option<&'static str> {
+    use model::RawRuleKind::*;
+    let name = match kind {
+        Skip => "skip",
+        Adapt(_) => "adapt",
+        Rename(_) => "rename",
+        Label(_) => "label",
+        Code(_) => "code",
+        Dive(_) => "dive",
+        Trim => "trim",
+        Lowercase => "lowercase",
+        Uppercase => "uppercase",
+        CollapseWhitespace => "collapse_whitespace",
+        Sanitize(_) => "sanitize",
+        _ => return None,
+    };
+    Some(name)
+}
+
 enum ContinueOnFail<T> {
     Ok(T),
     Err(syn::Error),
@@ -290,9 +382,128 @@ fn parse_raw_rule(input: syn::parse::ParseStream, ident: Ident) -> syn::Result<m
         _ => {}
     }
 
+    if !accepts_message(&ident) || !input.peek(syn::token::Paren) {
+        return Ok(model::RawRule {
+            span: ident.span(),
+            kind: parse_raw_rule_kind(input, &ident)?,
+            message: None,
+        });
+    }
+
+    // Split off the `message` argument, and parse the remaining arguments as usual.
+    let group = input.parse::<proc_macro2::Group>()?;
+    let (args, message) = split_message_arg(group.stream())?;
+    let rest = if args.is_empty() {
+        TokenStream2::new()
+    } else {
+        let mut rest = proc_macro2::Group::new(Delimiter::Parenthesis, args);
+        rest.set_span(group.span());
+        TokenTree::Group(rest).into()
+    };
+    let kind = Parser::parse2(
+        |input: syn::parse::ParseStream| parse_raw_rule_kind(input, &ident),
+        rest,
+    )?;
+
+    Ok(model::RawRule {
+        span: ident.span(),
+        kind,
+        message,
+    })
+}
+
+fn accepts_message(ident: &Ident) -> bool {
+    matches!(
+        ident.to_string().as_str(),
+        "required"
+            | "required_if"
+            | "required_with"
+            | "required_without"
+            | "ascii"
+            | "alphanumeric"
+            | "email"
+            | "url"
+            | "ip"
+            | "ipv4"
+            | "ipv6"
+            | "credit_card"
+            | "phone_number"
+            | "length"
+            | "matches"
+            | "not_equal"
+            | "greater_than"
+            | "greater_than_or_equal"
+            | "less_than"
+            | "less_than_or_equal"
+            | "range"
+            | "contains"
+            | "prefix"
+            | "suffix"
+            | "pattern"
+    )
+}
+
+/// Removes a top-level `message = <value>` argument from a comma-separated argument list.
+fn split_message_arg(args: TokenStream2) -> syn::Result<(TokenStream2, Option<model::Message>)> {
+    fn is_comma(tt: &TokenTree) -> bool {
+        matches!(tt, TokenTree::Punct(punct) if punct.as_char() == ',')
+    }
+
+    let mut message = None;
+    let mut out = Vec::new();
+    let mut tokens = args.into_iter().collect::<Vec<_>>().into_iter();
+    let mut at_arg_start = true;
+
+    while let Some(tt) = tokens.next() {
+        let is_message = at_arg_start
+            && matches!(&tt, TokenTree::Ident(ident) if ident == "message")
+            && matches!(
+                tokens.as_slice().first(),
+                Some(TokenTree::Punct(eq)) if eq.as_char() == '=' && eq.spacing() == Spacing::Alone
+            );
+        if !is_message {
+            at_arg_start = is_comma(&tt);
+            out.push(tt);
+            continue;
+        }
+
+        if message.is_some() {
+            return Err(syn::Error::new(tt.span(), "duplicate argument"));
+        }
+
+        // The value is parsed as an expression, so that commas inside of it
+        // (such as in closure parameters) don't end the argument early.
+        let _ = tokens.next();
+        let (value, rest) = Parser::parse2(
+            |input: syn::parse::ParseStream| {
+                Ok((
+                    input.parse::<model::Message>()?,
+                    input.parse::<TokenStream2>()?,
+                ))
+            },
+            tokens.collect(),
+        )?;
+        message = Some(value);
+
+        let mut rest = rest.into_iter().collect::<Vec<_>>();
+        if out.last().is_some_and(is_comma) {
+            out.pop();
+        } else if rest.first().is_some_and(is_comma) {
+            rest.remove(0);
+        }
+        tokens = rest.into_iter();
+    }
+
+    Ok((out.into_iter().collect(), message))
+}
+
+fn parse_raw_rule_kind(
+    input: syn::parse::ParseStream,
+    ident: &Ident,
+) -> syn::Result<model::RawRuleKind> {
     macro_rules! rules {
         (($input:ident, $ident:ident) {
-            $($name:literal => $rule:ident $(($content:ident))? $(( ? $content_opt:ident))?,)*
+            $($name:literal => $rule:ident $(($content:ident))? $(( ? $content_opt:ident))? $((= $content_eq:ident))?,)*
         }) => {
             match $ident.to_string().as_str() {
                 $(
@@ -315,10 +526,17 @@ fn parse_raw_rule(input: syn::parse::ParseStream, ident: Ident) -> syn::Result<m
                                 None
                             };
                         )?
-                        Ok(model::RawRule {
-                            span: $ident.span(),
-                            kind: model::RawRuleKind::$rule $(($content))? $(($content_opt))?
-                        })
+                        $(
+                            let $content_eq = if $input.peek(Token![=]) {
+                                let _ = $input.parse::<Token![=]>()?;
+                                $input.parse()?
+                            } else {
+                                let $content_eq;
+                                syn::parenthesized!($content_eq in $input);
+                                $content_eq.parse()?
+                            };
+                        )?
+                        Ok(model::RawRuleKind::$rule $(($content))? $(($content_opt))? $(($content_eq))?)
                     }
                 )*
                 _ => Err(syn::Error::new($ident.span(), "unrecognized validation rule")),
@@ -330,11 +548,14 @@ fn parse_raw_rule(input: syn::parse::ParseStream, ident: Ident) -> syn::Result<m
         (input, ident) {
             "skip" => Skip,
             "adapt" => Adapt(content),
-            "rename" => Rename(content),
-            // "message" => Message(content),
-            "code" => Code(content),
+            "rename" => Rename(= content),
+            "label" => Label(= content),
+            "code" => Code(= content),
             "dive" => Dive(? content),
             "required" => Required,
+            "required_if" => RequiredIf(content),
+            "required_with" => RequiredWith(content),
+            "required_without" => RequiredWithout(content),
             "ascii" => Ascii,
             "alphanumeric" => Alphanumeric,
             "email" => Email,
@@ -346,14 +567,26 @@ fn parse_raw_rule(input: syn::parse::ParseStream, ident: Ident) -> syn::Result<m
             "phone_number" => PhoneNumber,
             "length" => Length(content),
             "matches" => Matches(content),
+            "not_equal" => NotEqual(content),
+            "greater_than" => GreaterThan(content),
+            "greater_than_or_equal" => GreaterThanOrEqual(content),
+            "less_than" => LessThan(content),
+            "less_than_or_equal" => LessThanOrEqual(content),
             "range" => Range(content),
             "contains" => Contains(content),
             "prefix" => Prefix(content),
             "suffix" => Suffix(content),
             "pattern" => Pattern(content),
             "custom" => Custom(content),
+            "custom_async" => CustomAsync(content),
+            "trim" => Trim,
+            "lowercase" => Lowercase,
+            "uppercase" => Uppercase,
+            "collapse_whitespace" => CollapseWhitespace,
+            "sanitize" => Sanitize(content),
             "inner" => Inner(content),
             "if" => If(content),
+            "groups" => Groups(content),
         }
     }
 }
@@ -377,15 +610,15 @@ impl Parse for model::Str {
     }
 }
 
-// impl Parse for model::Message {
-//     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-//         if input.peek(syn::LitStr) {
-//             Ok(Self::Fmt(model::Str::parse(input)?))
-//         } else {
-//             Ok(Self::Func(syn::Expr::parse(input)?))
-//         }
-//     }
-// }
+impl Parse for model::Message {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::LitStr) {
+            Ok(Self::Fmt(model::Str::parse(input)?))
+        } else {
+            Ok(Self::Func(syn::Expr::parse(input)?))
+        }
+    }
+}
 
 impl Parse for model::RawLength {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {